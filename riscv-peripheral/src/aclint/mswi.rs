@@ -48,6 +48,21 @@ impl MSWI {
         // SAFETY: `hart_id` is valid for the target and is the current hart
         unsafe { MSIP::new(self.msip0.get_ptr().add(hart_id) as _) }
     }
+
+    /// Returns the `MSIP` register for the current HART, checking that it is a valid HART ID.
+    ///
+    /// Unlike [`MSWI::msip_mhartid`], it returns `Err(hart_id)` if the current HART ID
+    /// does not correspond to any HART ID of type `H`, instead of computing an invalid address.
+    ///
+    /// # Note
+    ///
+    /// This function determines the current HART ID by reading the [`riscv::register::mhartid`] CSR.
+    /// Thus, it can only be used in M-mode. For S-mode, use [`MSWI::msip`] instead.
+    #[inline]
+    pub fn try_msip_mhartid<H: HartIdNumber>(&self) -> Result<MSIP, usize> {
+        let hart_id = riscv::register::mhartid::read();
+        crate::common::hart_id_from_mhartid::<H>(hart_id).map(|hart_id| self.msip(hart_id))
+    }
 }
 
 unsafe_peripheral!(MSIP, u32, RW);
@@ -72,6 +87,121 @@ impl MSIP {
     }
 }
 
+/// One-shot barrier for synchronizing `N` HARTs using `MSIP` inter-processor interrupts.
+///
+/// The participating HARTs are those which HART ID numbers are in the range `0..N`. A designated leader HART waits until the other `N - 1` HARTs have arrived, and then releases them.
+/// HARTs signal their arrival by triggering a software interrupt on the leader, and the leader releases
+/// the rest of HARTs by triggering a software interrupt on each of them. While waiting, HARTs execute
+/// `wfi` instead of busy-spinning.
+///
+/// # Note
+///
+/// `wfi` only wakes up the HART if machine software interrupts are enabled in the `mie` register.
+/// However, the software interrupt must not be handled by a trap handler that clears `MSIP`, as the
+/// barrier relies on observing it. Thus, the usual setup is to set `mie.MSIE` while keeping `mstatus.MIE`
+/// cleared. All the `MSIP` registers of the participating HARTs are cleared when [`HartBarrier::wait`] returns.
+#[cfg(target_has_atomic = "ptr")]
+#[derive(Debug)]
+pub struct HartBarrier<const N: usize> {
+    mswi: MSWI,
+    leader: u16,
+    arrived: core::sync::atomic::AtomicUsize,
+    notified: core::sync::atomic::AtomicUsize,
+    released: core::sync::atomic::AtomicBool,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<const N: usize> HartBarrier<N> {
+    /// Creates a new barrier for `N` HARTs, where `leader` is the HART ID number of the leader HART.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `leader` is not lower than `N`.
+    #[inline]
+    pub const fn new(mswi: MSWI, leader: u16) -> Self {
+        assert!((leader as usize) < N);
+        Self {
+            mswi,
+            leader,
+            arrived: core::sync::atomic::AtomicUsize::new(0),
+            notified: core::sync::atomic::AtomicUsize::new(0),
+            released: core::sync::atomic::AtomicBool::new(false),
+        }
+    }
+
+    /// Returns `true` if the leader HART has already released the barrier.
+    #[inline]
+    pub fn is_released(&self) -> bool {
+        self.released.load(core::sync::atomic::Ordering::Acquire)
+    }
+
+    /// Blocks the HART which ID is `hart_id` until all the `N` HARTs have called this method.
+    ///
+    /// # Note
+    ///
+    /// Each participating HART must call this method exactly once.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the HART ID number of `hart_id` is not lower than `N`.
+    #[inline]
+    pub fn wait<H: HartIdNumber>(&self, hart_id: H) {
+        self.wait_with(hart_id, riscv::asm::wfi, core::hint::spin_loop);
+    }
+
+    /// Same as [`HartBarrier::wait`], but calling `idle` instead of `wfi` while waiting for an IPI,
+    /// and `spin` instead of [`core::hint::spin_loop`] while busy waiting.
+    fn wait_with<H: HartIdNumber, F: FnMut(), S: FnMut()>(
+        &self,
+        hart_id: H,
+        mut idle: F,
+        mut spin: S,
+    ) {
+        use core::sync::atomic::Ordering;
+
+        assert!((hart_id.number() as usize) < N);
+        let msip = self.mswi.msip(hart_id);
+        if hart_id.number() == self.leader {
+            // Clear MSIP before checking the counter so no arrival is missed
+            loop {
+                msip.unpend();
+                if self.arrived.load(Ordering::Acquire) >= N - 1 {
+                    break;
+                }
+                idle();
+            }
+            // Wait for the IPIs of the last followers, so that clearing MSIP cannot miss any of them.
+            // A follower counts its IPI right after raising it, so we spin instead of sleeping:
+            // the IPI may have already been cleared, and no other IPI would wake us up.
+            while self.notified.load(Ordering::Acquire) < N - 1 {
+                spin();
+            }
+            msip.unpend();
+            for i in (0..N as u16).filter(|&i| i != self.leader) {
+                self.mswi.msip(H::from_number(i).unwrap()).pend();
+            }
+            self.released.store(true, Ordering::Release);
+        } else {
+            // Count the arrival before raising the IPI. Otherwise, the leader could clear the IPI,
+            // read the old counter, and go back to sleep without any further IPI to wake it up.
+            self.arrived.fetch_add(1, Ordering::Release);
+            self.notify_leader::<H>();
+            while !self.is_released() {
+                idle();
+            }
+            // The leader pends our MSIP before releasing the barrier
+            msip.unpend();
+        }
+    }
+
+    /// Triggers a software interrupt on the leader HART and records it.
+    fn notify_leader<H: HartIdNumber>(&self) {
+        self.mswi.msip(H::from_number(self.leader).unwrap()).pend();
+        self.notified
+            .fetch_add(1, core::sync::atomic::Ordering::Release);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::test::HartId;
@@ -97,4 +227,126 @@ mod test {
             assert_eq!(raw_reg[i as usize], 0);
         }
     }
+
+    #[test]
+    fn test_hart_barrier() {
+        extern crate std;
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        const N: usize = HartId::MAX_HART_ID_NUMBER as usize + 1;
+
+        // atomics to emulate the MSIP registers of the HARTs running in different threads
+        let raw_reg = [AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0)];
+        // SAFETY: valid memory address
+        let mswi = unsafe { MSWI::new(raw_reg.as_ptr() as _) };
+        let barrier = HartBarrier::<N>::new(mswi, HartId::H0.number());
+
+        std::thread::scope(|s| {
+            for hart_id in [HartId::H1, HartId::H2] {
+                let barrier = &barrier;
+                s.spawn(move || {
+                    barrier.wait_with(hart_id, std::thread::yield_now, core::hint::spin_loop);
+                    assert!(barrier.is_released());
+                });
+            }
+            // the leader waits until the two other HARTs arrive
+            barrier.wait_with(HartId::H0, std::thread::yield_now, core::hint::spin_loop);
+            assert!(barrier.is_released());
+        });
+
+        // all the MSIP registers are cleared once the barrier is released
+        assert!(raw_reg.iter().all(|reg| reg.load(Ordering::Relaxed) == 0));
+    }
+
+    #[test]
+    fn test_hart_barrier_arrival_order() {
+        use core::sync::atomic::Ordering;
+
+        let mut raw_reg = [0u32; 2];
+        // SAFETY: valid memory address
+        let mswi = unsafe { MSWI::new(raw_reg.as_mut_ptr() as _) };
+        let barrier = HartBarrier::<2>::new(mswi, HartId::H0.number());
+        let leader_msip = mswi.msip(HartId::H0);
+
+        // The follower counts its arrival during a spurious wakeup of the leader, and raises the
+        // IPI once the leader already observed the counter. The leader must not sleep waiting
+        // for that IPI, as it is the last one it would receive.
+        let (mut idle_calls, mut spin_calls) = (0, 0);
+        barrier.wait_with(
+            HartId::H0,
+            || {
+                idle_calls += 1;
+                barrier.arrived.fetch_add(1, Ordering::Release);
+            },
+            || {
+                spin_calls += 1;
+                barrier.notify_leader::<HartId>();
+            },
+        );
+        assert_eq!((idle_calls, spin_calls), (1, 1));
+        assert!(barrier.is_released());
+        // the late IPI is cleared, and the follower is released
+        assert!(!leader_msip.is_pending());
+        assert!(mswi.msip(HartId::H1).is_pending());
+    }
+
+    #[test]
+    fn test_hart_barrier_late_notification() {
+        use core::sync::atomic::Ordering;
+
+        let mut raw_reg = [0u32; 2];
+        // SAFETY: valid memory address
+        let mswi = unsafe { MSWI::new(raw_reg.as_mut_ptr() as _) };
+        let barrier = HartBarrier::<2>::new(mswi, HartId::H0.number());
+        let leader_msip = mswi.msip(HartId::H0);
+
+        // The follower arrives and raises the IPI while the leader sleeps, but it is preempted
+        // before recording the notification. The leader clears its MSIP and observes the arrival
+        // in the meantime, so it must keep running until the follower records the notification.
+        let (mut idle_calls, mut spin_calls) = (0, 0);
+        barrier.wait_with(
+            HartId::H0,
+            || {
+                idle_calls += 1;
+                if idle_calls == 1 {
+                    barrier.arrived.fetch_add(1, Ordering::Release);
+                    leader_msip.pend();
+                }
+                // `wfi` only returns if there is a pending IPI
+                assert!(leader_msip.is_pending(), "lost wakeup");
+            },
+            || {
+                spin_calls += 1;
+                // the leader already cleared the IPI before the follower records it
+                assert!(!leader_msip.is_pending());
+                barrier.notified.fetch_add(1, Ordering::Release);
+            },
+        );
+        assert_eq!((idle_calls, spin_calls), (1, 1));
+        assert!(barrier.is_released());
+        assert!(!leader_msip.is_pending());
+        assert!(mswi.msip(HartId::H1).is_pending());
+    }
+
+    #[test]
+    fn test_hart_barrier_single_hart() {
+        let mut raw_reg = [0u32; 1];
+        // SAFETY: valid memory address
+        let mswi = unsafe { MSWI::new(raw_reg.as_mut_ptr() as _) };
+        let barrier = HartBarrier::<1>::new(mswi, HartId::H0.number());
+
+        // the leader does not wait for anyone
+        barrier.wait_with(HartId::H0, || unreachable!(), || unreachable!());
+        assert!(barrier.is_released());
+        assert_eq!(raw_reg[0], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hart_barrier_invalid_leader() {
+        let mut raw_reg = [0u32; 2];
+        // SAFETY: valid memory address
+        let mswi = unsafe { MSWI::new(raw_reg.as_mut_ptr() as _) };
+        HartBarrier::<2>::new(mswi, HartId::H2.number());
+    }
 }
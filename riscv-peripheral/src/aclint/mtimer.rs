@@ -50,11 +50,67 @@ impl MTIMER {
         // SAFETY: `hart_id` is valid for the target and is the current hart
         unsafe { MTIMECMP::new(self.mtimecmp0.get_ptr().add(hart_id) as _) }
     }
+
+    /// Returns the `MTIMECMP` register for the current HART, checking that it is a valid HART ID.
+    ///
+    /// Unlike [`MTIMER::mtimecmp_mhartid`], it returns `Err(hart_id)` if the current HART ID
+    /// does not correspond to any HART ID of type `H`, instead of computing an invalid address.
+    ///
+    /// # Note
+    ///
+    /// This function determines the current HART ID by reading the [`riscv::register::mhartid`] CSR.
+    /// Thus, it can only be used in M-mode. For S-mode, use [`MTIMER::mtimecmp`] instead.
+    #[inline]
+    pub fn try_mtimecmp_mhartid<H: HartIdNumber>(&self) -> Result<MTIMECMP, usize> {
+        let hart_id = riscv::register::mhartid::read();
+        crate::common::hart_id_from_mhartid::<H>(hart_id).map(|hart_id| self.mtimecmp(hart_id))
+    }
 }
 
 // MTIMECMP register.
 safe_peripheral!(MTIMECMP, u64, RW);
 
+impl MTIMECMP {
+    /// Reads the value of the comparator.
+    ///
+    /// On 32-bit targets, the register is read as two halves. To avoid returning a torn value
+    /// if another context updates the comparator in between, the high half is read before and
+    /// after the low half, and the read is retried until both reads of the high half match.
+    #[inline]
+    pub fn read(self) -> u64 {
+        match () {
+            #[cfg(target_pointer_width = "32")]
+            () => {
+                let ptr = self.register.get_ptr() as *const u32;
+                // SAFETY: valid address and register is readable (RISC-V is little-endian)
+                unsafe { read_hi_lo_hi(|| ptr.read_volatile(), || ptr.add(1).read_volatile()) }
+            }
+            #[cfg(not(target_pointer_width = "32"))]
+            () => self.register.read(),
+        }
+    }
+
+    /// Returns `true` if a timer interrupt is due at time `mtime`,
+    /// i.e., if `mtime` is greater than or equal to the value of the comparator.
+    #[inline]
+    pub fn is_due(self, mtime: u64) -> bool {
+        mtime >= self.read()
+    }
+}
+
+/// Reads a 64-bit value as two 32-bit halves, retrying until the high half is stable.
+#[cfg(any(test, target_pointer_width = "32"))]
+#[inline]
+fn read_hi_lo_hi<L: FnMut() -> u32, H: FnMut() -> u32>(mut read_lo: L, mut read_hi: H) -> u64 {
+    loop {
+        let hi = read_hi();
+        let lo = read_lo();
+        if hi == read_hi() {
+            return ((hi as u64) << 32) | lo as u64;
+        }
+    }
+}
+
 // MTIME register.
 safe_peripheral!(MTIME, u64, RW);
 
@@ -89,4 +145,43 @@ mod test {
             &raw_mtime as *const u64 as _
         );
     }
+
+    #[test]
+    fn check_mtimecmp_is_due() {
+        let mut raw_mtimecmp = 0u64;
+        // SAFETY: valid memory address
+        let mtimecmp = unsafe { MTIMECMP::new(core::ptr::addr_of_mut!(raw_mtimecmp) as _) };
+
+        mtimecmp.write(0x1_0000_0100);
+        assert_eq!(mtimecmp.read(), 0x1_0000_0100);
+        for (mtime, due) in [
+            (0, false),
+            (0xFFFF_FFFF, false),
+            (0x1_0000_00FF, false),
+            (0x1_0000_0100, true),
+            (0x1_0000_0101, true),
+            (u64::MAX, true),
+        ] {
+            assert_eq!(mtimecmp.is_due(mtime), due);
+        }
+
+        // a disarmed comparator is never due before MTIME wraps
+        mtimecmp.write(u64::MAX);
+        assert!(!mtimecmp.is_due(u64::MAX - 1));
+    }
+
+    #[test]
+    fn check_read_hi_lo_hi() {
+        assert_eq!(
+            read_hi_lo_hi(|| 0x89AB_CDEF, || 0x0123_4567),
+            0x0123_4567_89AB_CDEF
+        );
+
+        // the comparator is updated from 0x0_0000_0000 to 0x1_0000_0000 while reading the low half
+        let mut his = [0, 1, 1, 1].into_iter();
+        let mut los = [0x0000_0000, 0x0000_0000].into_iter();
+        let value = read_hi_lo_hi(|| los.next().unwrap(), || his.next().unwrap());
+        assert_eq!(value, 0x1_0000_0000);
+        assert_eq!(his.next(), None);
+    }
 }
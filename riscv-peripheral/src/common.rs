@@ -177,8 +177,11 @@ bitwise_reg!(i128);
 bitwise_reg!(isize);
 
 /// Macro to provide atomic bit-wise operations to integer number registers.
+///
+/// The optional `$FUNCT3` argument selects the `amocas` width (`0x2` for `amocas.w`,
+/// `0x3` for `amocas.d`) used by `atomic_compare_exchange` when the `zacas` feature is enabled.
 macro_rules! bitwise_atomic_reg {
-    ($TYPE: ty, $ATOMIC: ty) => {
+    ($TYPE: ty, $ATOMIC: ty $(, $FUNCT3: literal)?) => {
         impl<A: Read + Write> Reg<$TYPE, A> {
             /// Creates a new atomic reference to the register.
             ///
@@ -214,6 +217,63 @@ macro_rules! bitwise_atomic_reg {
                 // SAFETY: guaranteed by the caller
                 unsafe { self.as_atomic() }.fetch_or(1 << n, order);
             }
+
+            /// Stores `new` into the register if its current value is `current`.
+            ///
+            /// The return value is a result indicating whether the new value was written and
+            /// containing the previous value. On success, this value is guaranteed to be `current`.
+            ///
+            /// # Note
+            ///
+            /// If the `zacas` feature is enabled, 32-bit registers (and 64-bit registers on RV64 targets)
+            /// are updated with a single `amocas.w.aqrl` (`amocas.d.aqrl`) instruction. In this case,
+            /// `success` and `failure` are ignored, as the instruction is already sequentially consistent.
+            ///
+            /// Otherwise, it falls back to [`core::sync::atomic`]'s `compare_exchange`.
+            /// On RISC-V targets with the A extension, this compiles down to an `lr`/`sc` loop.
+            ///
+            /// # Safety
+            ///
+            /// * Register must be properly aligned **for atomic operations**.
+            /// * The register must not be accessed through non-atomic operations until this function returns.
+            /// * If the `zacas` feature is enabled, the target must implement the Zacas extension.
+            #[inline]
+            #[allow(unreachable_code)] // the fallback is unreachable when using Zacas
+            pub unsafe fn atomic_compare_exchange(
+                &self,
+                current: $TYPE,
+                new: $TYPE,
+                success: core::sync::atomic::Ordering,
+                failure: core::sync::atomic::Ordering,
+            ) -> Result<$TYPE, $TYPE> {
+                $(
+                    #[cfg(all(
+                        feature = "zacas",
+                        any(target_arch = "riscv32", target_arch = "riscv64")
+                    ))]
+                    {
+                        let _ = (success, failure);
+                        let mut previous = current;
+                        // SAFETY: guaranteed by the caller
+                        unsafe {
+                            core::arch::asm!(
+                                concat!(".insn r 0x2F, ", $FUNCT3, ", 0x17, {0}, {2}, {1}"),
+                                inout(reg) previous,
+                                in(reg) new,
+                                in(reg) self.ptr,
+                                options(nostack),
+                            )
+                        };
+                        return if previous == current {
+                            Ok(previous)
+                        } else {
+                            Err(previous)
+                        };
+                    }
+                )?
+                // SAFETY: guaranteed by the caller
+                unsafe { self.as_atomic() }.compare_exchange(current, new, success, failure)
+            }
         }
     };
 }
@@ -223,21 +283,25 @@ bitwise_atomic_reg!(u8, core::sync::atomic::AtomicU8);
 #[cfg(target_has_atomic = "16")]
 bitwise_atomic_reg!(u16, core::sync::atomic::AtomicU16);
 #[cfg(target_has_atomic = "32")]
-bitwise_atomic_reg!(u32, core::sync::atomic::AtomicU32);
+bitwise_atomic_reg!(u32, core::sync::atomic::AtomicU32, "0x2");
 #[cfg(target_has_atomic = "64")]
-bitwise_atomic_reg!(u64, core::sync::atomic::AtomicU64);
-#[cfg(target_has_atomic = "ptr")]
-bitwise_atomic_reg!(usize, core::sync::atomic::AtomicUsize);
+bitwise_atomic_reg!(u64, core::sync::atomic::AtomicU64, "0x3");
+#[cfg(all(target_has_atomic = "ptr", target_pointer_width = "32"))]
+bitwise_atomic_reg!(usize, core::sync::atomic::AtomicUsize, "0x2");
+#[cfg(all(target_has_atomic = "ptr", target_pointer_width = "64"))]
+bitwise_atomic_reg!(usize, core::sync::atomic::AtomicUsize, "0x3");
 #[cfg(target_has_atomic = "8")]
 bitwise_atomic_reg!(i8, core::sync::atomic::AtomicI8);
 #[cfg(target_has_atomic = "16")]
 bitwise_atomic_reg!(i16, core::sync::atomic::AtomicI16);
 #[cfg(target_has_atomic = "32")]
-bitwise_atomic_reg!(i32, core::sync::atomic::AtomicI32);
+bitwise_atomic_reg!(i32, core::sync::atomic::AtomicI32, "0x2");
 #[cfg(target_has_atomic = "64")]
-bitwise_atomic_reg!(i64, core::sync::atomic::AtomicI64);
-#[cfg(target_has_atomic = "ptr")]
-bitwise_atomic_reg!(isize, core::sync::atomic::AtomicIsize);
+bitwise_atomic_reg!(i64, core::sync::atomic::AtomicI64, "0x3");
+#[cfg(all(target_has_atomic = "ptr", target_pointer_width = "32"))]
+bitwise_atomic_reg!(isize, core::sync::atomic::AtomicIsize, "0x2");
+#[cfg(all(target_has_atomic = "ptr", target_pointer_width = "64"))]
+bitwise_atomic_reg!(isize, core::sync::atomic::AtomicIsize, "0x3");
 
 /// Macro to define the archetypal behavior of registers.
 macro_rules! peripheral {
@@ -398,6 +462,70 @@ macro_rules! unsafe_peripheral {
 
 pub(crate) use {peripheral, safe_peripheral, unsafe_peripheral};
 
+/// Converts the raw value of the `mhartid` CSR to a HART ID number of type `H`.
+///
+/// It returns `Err(mhartid)` if `mhartid` does not correspond to any variant of `H`
+/// (e.g., the system has more HARTs than those configured for the peripheral).
+#[inline]
+pub(crate) fn hart_id_from_mhartid<H: riscv_pac::HartIdNumber>(mhartid: usize) -> Result<H, usize> {
+    u16::try_from(mhartid)
+        .ok()
+        .and_then(|number| H::from_number(number).ok())
+        .ok_or(mhartid)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aclint::test::HartId;
+
+    #[test]
+    fn test_hart_id_from_mhartid() {
+        assert_eq!(hart_id_from_mhartid(0), Ok(HartId::H0));
+        assert_eq!(hart_id_from_mhartid(2), Ok(HartId::H2));
+        // HART IDs beyond the configured ones are rejected
+        assert_eq!(hart_id_from_mhartid::<HartId>(3), Err(3));
+        assert_eq!(hart_id_from_mhartid::<HartId>(0x1_0000), Err(0x1_0000));
+    }
+
+    #[test]
+    fn test_atomic_compare_exchange() {
+        use core::sync::atomic::Ordering;
+
+        let mut raw_reg = 0b0101u32;
+        // SAFETY: valid memory address
+        let reg: Reg<u32, RW> = unsafe { Reg::new(&mut raw_reg) };
+
+        // successful CAS
+        let res = unsafe {
+            reg.atomic_compare_exchange(0b0101, 0b0111, Ordering::SeqCst, Ordering::SeqCst)
+        };
+        assert_eq!(res, Ok(0b0101));
+        assert_eq!(reg.read(), 0b0111);
+
+        // failing CAS leaves the register untouched
+        let res = unsafe {
+            reg.atomic_compare_exchange(0b0101, 0b1111, Ordering::SeqCst, Ordering::SeqCst)
+        };
+        assert_eq!(res, Err(0b0111));
+        assert_eq!(reg.read(), 0b0111);
+
+        // CAS loop, as used to update a shared register from several HARTs
+        let mut current = reg.read();
+        while let Err(previous) = unsafe {
+            reg.atomic_compare_exchange(
+                current,
+                current | 1 << 31,
+                Ordering::SeqCst,
+                Ordering::Relaxed,
+            )
+        } {
+            current = previous;
+        }
+        assert_eq!(reg.read(), 0b0111 | 1 << 31);
+    }
+}
+
 mod sealed {
     use super::*;
     pub trait Access {}
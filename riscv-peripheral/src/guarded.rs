@@ -0,0 +1,245 @@
+//! Critical-section protected access to interrupt controller registers.
+//!
+//! Some configuration methods of the PLIC and CLINT registers perform non-atomic read-modify-write
+//! operations (e.g., [`ENABLES::enable`]) or split writes (e.g., `MTIMECMP` in 32-bit targets).
+//! If an interrupt handler or another HART modifies the same register in the middle of one of
+//! these operations, one of the updates may be lost. [`Guarded`] wraps a register proxy and runs
+//! its configuration methods inside a [`critical_section`], so that they cannot interleave.
+//!
+//! # Note
+//!
+//! The guarantees are only as strong as the `critical-section` implementation of the target.
+//! In multi-HART systems, it must exclude other HARTs too (i.e., not only disable interrupts).
+//! Accesses that bypass the wrapper are not protected.
+
+use crate::aclint::{mswi::MSIP, mtimer::MTIMECMP};
+use crate::plic::{
+    enables::ENABLES, priorities::PRIORITIES, threshold::THRESHOLD, InterruptNumber, PriorityNumber,
+};
+
+/// Register proxy whose configuration methods run inside a critical section.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Guarded<T> {
+    inner: T,
+}
+
+impl<T: Copy> Guarded<T> {
+    /// Wraps a register proxy.
+    #[inline]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the underlying register proxy. Accesses through it are not protected.
+    #[inline]
+    pub const fn inner(&self) -> T {
+        self.inner
+    }
+
+    /// Runs `f` with the underlying register proxy inside a critical section.
+    ///
+    /// Use it to group several accesses that must not interleave with other guarded accesses.
+    #[inline]
+    pub fn lock<R, F: FnOnce(T) -> R>(&self, f: F) -> R {
+        critical_section::with(|_| f(self.inner))
+    }
+}
+
+impl Guarded<ENABLES> {
+    /// Checks if an interrupt source is enabled for the PLIC context.
+    #[inline]
+    pub fn is_enabled<I: InterruptNumber>(&self, source: I) -> bool {
+        self.inner.is_enabled(source)
+    }
+
+    /// Enables an interrupt source for the PLIC context inside a critical section.
+    ///
+    /// # Safety
+    ///
+    /// * Enabling an interrupt source can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn enable<I: InterruptNumber>(&self, source: I) {
+        self.lock(|enables| enables.enable(source));
+    }
+
+    /// Disables an interrupt source for the PLIC context inside a critical section.
+    #[inline]
+    pub fn disable<I: InterruptNumber>(&self, source: I) {
+        self.lock(|enables| enables.disable(source));
+    }
+
+    /// Enables all the external interrupt sources for the PLIC context inside a critical section.
+    ///
+    /// # Safety
+    ///
+    /// * Enabling all interrupt sources can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn enable_all<I: InterruptNumber>(&self) {
+        self.lock(|enables| enables.enable_all::<I>());
+    }
+
+    /// Disables all the external interrupt sources for the PLIC context inside a critical section.
+    #[inline]
+    pub fn disable_all<I: InterruptNumber>(&self) {
+        self.lock(|enables| enables.disable_all::<I>());
+    }
+}
+
+impl Guarded<PRIORITIES> {
+    /// Sets the priority level of a given interrupt source inside a critical section.
+    ///
+    /// # Safety
+    ///
+    /// Changing the priority level can break priority-based critical sections.
+    #[inline]
+    pub unsafe fn set_priority<I: InterruptNumber, P: PriorityNumber>(
+        &self,
+        source: I,
+        priority: P,
+    ) {
+        self.lock(|priorities| priorities.set_priority(source, priority));
+    }
+
+    /// Resets all the priority levels of all the external interrupt sources to 0 inside a critical section.
+    #[inline]
+    pub fn reset<I: InterruptNumber>(&self) {
+        self.lock(|priorities| priorities.reset::<I>());
+    }
+}
+
+impl Guarded<THRESHOLD> {
+    /// Sets the priority threshold level inside a critical section.
+    ///
+    /// # Safety
+    ///
+    /// Changing the priority threshold can break priority-based critical sections.
+    #[inline]
+    pub unsafe fn set_threshold<P: PriorityNumber>(&self, threshold: P) {
+        self.lock(|reg| reg.set_threshold(threshold));
+    }
+
+    /// Resets the priority threshold level to 0 inside a critical section.
+    #[inline]
+    pub fn reset(&self) {
+        self.lock(|reg| reg.reset());
+    }
+}
+
+impl Guarded<MTIMECMP> {
+    /// Writes the comparator inside a critical section.
+    ///
+    /// In 32-bit targets, this prevents other guarded accesses from observing
+    /// or overwriting half of the new value.
+    #[inline]
+    pub fn write(&self, value: u64) {
+        self.lock(|mtimecmp| mtimecmp.write(value));
+    }
+}
+
+impl Guarded<MSIP> {
+    /// Triggers a machine software interrupt inside a critical section.
+    #[inline]
+    pub fn pend(&self) {
+        self.lock(|msip| msip.pend());
+    }
+
+    /// Unpends a machine software interrupt inside a critical section.
+    #[inline]
+    pub fn unpend(&self) {
+        self.lock(|msip| msip.unpend());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plic::test::{Interrupt, Priority};
+
+    #[test]
+    fn test_guarded_enables() {
+        extern crate std;
+
+        // slice to emulate the interrupt enables register
+        let mut raw_reg = [0u32; 32];
+        let address = raw_reg.as_mut_ptr() as usize;
+
+        // several contexts (emulated with threads) toggle different sources of the same register
+        let sources = [Interrupt::I1, Interrupt::I2, Interrupt::I3, Interrupt::I4];
+        std::thread::scope(|s| {
+            for source in sources {
+                s.spawn(move || {
+                    // SAFETY: valid memory address
+                    let enables = Guarded::new(unsafe { ENABLES::new(address) });
+                    for _ in 0..1_000 {
+                        unsafe { enables.enable(source) };
+                        enables.disable(source);
+                    }
+                    unsafe { enables.enable(source) };
+                });
+            }
+        });
+
+        // no read-modify-write operation was lost
+        // SAFETY: valid memory address
+        let enables = Guarded::new(unsafe { ENABLES::new(address) });
+        for source in sources {
+            assert!(enables.is_enabled(source));
+        }
+        assert_eq!(raw_reg[0], 0b11110);
+
+        enables.disable_all::<Interrupt>();
+        assert_eq!(raw_reg[0], 0);
+    }
+
+    #[test]
+    fn test_guarded_config() {
+        let mut raw_priorities = [0u32; 5];
+        let mut raw_threshold = 0u32;
+        let mut raw_mtimecmp = 0u64;
+        let mut raw_msip = 0u32;
+        // SAFETY: valid memory addresses
+        let (priorities, threshold, mtimecmp, msip) = unsafe {
+            (
+                Guarded::new(PRIORITIES::new(raw_priorities.as_mut_ptr() as _)),
+                Guarded::new(THRESHOLD::new(&mut raw_threshold as *mut u32 as _)),
+                Guarded::new(MTIMECMP::new(core::ptr::addr_of_mut!(raw_mtimecmp) as _)),
+                Guarded::new(MSIP::new(core::ptr::addr_of_mut!(raw_msip) as _)),
+            )
+        };
+
+        unsafe { priorities.set_priority(Interrupt::I2, Priority::P3) };
+        assert_eq!(
+            priorities
+                .inner()
+                .get_priority::<_, Priority>(Interrupt::I2),
+            Priority::P3
+        );
+        priorities.reset::<Interrupt>();
+        assert_eq!(
+            priorities
+                .inner()
+                .get_priority::<_, Priority>(Interrupt::I2),
+            Priority::P0
+        );
+
+        unsafe { threshold.set_threshold(Priority::P2) };
+        assert_eq!(threshold.inner().get_threshold::<Priority>(), Priority::P2);
+        threshold.reset();
+        assert_eq!(threshold.inner().get_threshold::<Priority>(), Priority::P0);
+
+        mtimecmp.write(0x1_0000_0100);
+        assert_eq!(mtimecmp.inner().read(), 0x1_0000_0100);
+
+        msip.pend();
+        assert!(msip.inner().is_pending());
+        msip.unpend();
+        assert!(!msip.inner().is_pending());
+
+        // several accesses in a single critical section
+        let due = mtimecmp.lock(|mtimecmp| {
+            mtimecmp.write(100);
+            mtimecmp.is_due(100)
+        });
+        assert!(due);
+    }
+}
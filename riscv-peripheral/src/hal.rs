@@ -3,3 +3,4 @@
 pub use embedded_hal::*; // re-export embedded-hal to allow macros to use it
 
 pub mod aclint; // ACLINT and CLINT peripherals
+pub mod time; // conversions between time units and timer ticks
@@ -1,9 +1,27 @@
 //! Delay trait implementation for (A)CLINT peripherals
 
-use crate::aclint::mtimer::MTIME;
+use super::time::{duration_to_ticks_ceil, ns_to_ticks_ceil, ticks_to_duration};
+use crate::aclint::mtimer::{MTIME, MTIMECMP};
 pub use crate::hal::delay::DelayNs;
+use core::time::Duration;
 
 /// Delay implementation for (A)CLINT peripherals.
+///
+/// It only holds a handle to the read-only `MTIME` register and its frequency.
+/// Thus, it is `Copy`, `Send`, and `Sync`, and it can be passed by value to several drivers.
+///
+/// As `MTIME` is shared by all the HARTs, the same `Delay` can also be shared across HARTs
+/// (e.g., in a `static`) or constructed independently on each HART. In contrast, [`Alarm`] and
+/// the asynchronous delay are bound to the `MTIMECMP` register of a given HART.
+///
+/// # Note
+///
+/// [`MTIME`] is a `Copy` wrapper around the address of the register, so `Delay` takes it by value.
+/// Borrowing it instead would store a pointer to a pointer of the same size and tie every `Delay`
+/// to a lifetime. Thus, a `Delay` is as large as a pointer plus the frequency, and creating one per
+/// driver costs no more than passing these two words around. If the frequency is known at compile
+/// time, [`FixedDelay`] is only as large as a pointer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Delay {
     mtime: MTIME,
     freq: usize,
@@ -16,6 +34,48 @@ impl Delay {
         Self { mtime, freq }
     }
 
+    /// Estimates the frequency of the `MTIME` register at runtime.
+    ///
+    /// It busy-loops for `reference_cycles` CPU cycles (measured with the `cycle` CSR)
+    /// while sampling `MTIME`, and scales the elapsed ticks by `cpu_freq` (in Hz).
+    /// The returned value can be used as the `freq` argument of [`Delay::new`].
+    ///
+    /// # Note
+    ///
+    /// The estimate is only as accurate as `cpu_freq`. Besides, the resolution is limited to
+    /// one `MTIME` tick per `reference_cycles`, so the longer the reference delay, the more
+    /// accurate the result. Interrupts and frequency scaling during calibration bias the result.
+    /// The `cycle` CSR must be accessible from the current privilege mode.
+    #[inline]
+    pub fn calibrate(mtime: MTIME, cpu_freq: usize, reference_cycles: u64) -> usize {
+        Self::calibrate_with(
+            mtime,
+            cpu_freq,
+            reference_cycles,
+            riscv::register::cycle::read64,
+        )
+    }
+
+    /// Same as [`Delay::calibrate`], but reading the CPU cycles with `read_cycles`.
+    fn calibrate_with<F: FnMut() -> u64>(
+        mtime: MTIME,
+        cpu_freq: usize,
+        reference_cycles: u64,
+        mut read_cycles: F,
+    ) -> usize {
+        let (c0, t0) = (read_cycles(), mtime.read());
+        let mut c1 = c0;
+        while c1.wrapping_sub(c0) < reference_cycles {
+            c1 = read_cycles();
+        }
+        let (cycles, ticks) = (c1.wrapping_sub(c0), mtime.read().wrapping_sub(t0));
+        if cycles == 0 {
+            return 0;
+        }
+        let freq = ticks as u128 * cpu_freq as u128 / cycles as u128;
+        usize::try_from(freq).unwrap_or(usize::MAX)
+    }
+
     /// Returns the frequency of the `MTIME` register.
     #[inline]
     pub const fn get_freq(&self) -> usize {
@@ -33,14 +93,697 @@ impl Delay {
     pub const fn get_mtime(&self) -> MTIME {
         self.mtime
     }
+
+    /// Blocks the current HART for at least the given [`Duration`].
+    ///
+    /// Unlike [`DelayNs::delay_ns`], this method is not limited to `u32::MAX` nanoseconds.
+    /// The number of ticks is computed as in [`Delay::ticks_for_ns`] and saturates at `u64::MAX`.
+    #[inline]
+    pub fn delay(&mut self, duration: Duration) {
+        self.wait_ticks(self.duration_to_ticks(duration));
+    }
+
+    /// Returns the number of `MTIME` ticks that [`DelayNs::delay_ns`] busy-waits for `ns` nanoseconds.
+    ///
+    /// The conversion rounds up and does not depend on the state of `MTIME`. As the delay starts at
+    /// an arbitrary point within an `MTIME` tick, the first tick only lasts a fraction of a period, so
+    /// one more tick is added. Thus, the actual delay lasts between `ticks - 1` and `ticks` `MTIME`
+    /// periods, plus the polling overhead, and it is never shorter than `ns` nanoseconds.
+    #[inline]
+    pub const fn ticks_for_ns(&self, ns: u32) -> u64 {
+        wait_len(ns_to_ticks_ceil(ns as u64, self.freq))
+    }
+
+    /// Converts a [`Duration`] to the number of `MTIME` ticks to wait for, saturating at `u64::MAX`.
+    #[inline]
+    fn duration_to_ticks(&self, duration: Duration) -> u64 {
+        wait_len(duration_to_ticks_ceil(duration, self.freq))
+    }
+
+    /// Busy-waits until `n_ticks` ticks of the `MTIME` register have elapsed.
+    #[inline]
+    fn wait_ticks(&self, n_ticks: u64) {
+        wait_ticks(self.mtime, n_ticks);
+    }
+
+    /// Converts this delay into a [`YieldDelay`] that calls `hook` between `MTIME` polls.
+    #[inline]
+    pub const fn with_yield<F: FnMut()>(self, hook: F) -> YieldDelay<F> {
+        YieldDelay { delay: self, hook }
+    }
 }
 
 impl DelayNs for Delay {
     #[inline]
     fn delay_ns(&mut self, ns: u32) {
-        let t0 = self.mtime.read();
-        let ns_64: u64 = ns.into();
-        let n_ticks = ns_64 * self.freq as u64 / 1_000_000_000;
-        while self.mtime.read().wrapping_sub(t0) < n_ticks {}
+        self.wait_ticks(self.ticks_for_ns(ns));
+    }
+}
+
+impl<const FREQ: usize> From<FixedDelay<FREQ>> for Delay {
+    #[inline]
+    fn from(delay: FixedDelay<FREQ>) -> Self {
+        Self::new(delay.mtime, FREQ)
+    }
+}
+
+/// Delay implementation for (A)CLINT peripherals with an `MTIME` frequency of `FREQ` Hz known at compile time.
+///
+/// It behaves as [`Delay`], but the frequency is part of the type. Thus, the compiler can fold
+/// the conversion from time units to `MTIME` ticks, avoiding a runtime multiplication and division.
+/// Use [`Delay`] if the frequency is only known at runtime (e.g., after [`Delay::calibrate`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FixedDelay<const FREQ: usize> {
+    mtime: MTIME,
+}
+
+impl<const FREQ: usize> FixedDelay<FREQ> {
+    /// Creates a new `FixedDelay` instance.
+    #[inline]
+    pub const fn new(mtime: MTIME) -> Self {
+        Self { mtime }
+    }
+
+    /// Returns the frequency of the `MTIME` register.
+    #[inline]
+    pub const fn get_freq(&self) -> usize {
+        FREQ
+    }
+
+    /// Returns the `MTIME` register.
+    #[inline]
+    pub const fn get_mtime(&self) -> MTIME {
+        self.mtime
+    }
+
+    /// Blocks the current HART for at least the given [`Duration`].
+    ///
+    /// Unlike [`DelayNs::delay_ns`], this method is not limited to `u32::MAX` nanoseconds.
+    /// The number of ticks is computed as in [`Delay::ticks_for_ns`] and saturates at `u64::MAX`.
+    #[inline]
+    pub fn delay(&mut self, duration: Duration) {
+        wait_ticks(self.mtime, wait_len(duration_to_ticks_ceil(duration, FREQ)));
+    }
+
+    /// Returns the number of `MTIME` ticks that [`DelayNs::delay_ns`] busy-waits for `ns` nanoseconds.
+    ///
+    /// See [`Delay::ticks_for_ns`] for more information.
+    #[inline]
+    pub const fn ticks_for_ns(&self, ns: u32) -> u64 {
+        wait_len(ns_to_ticks_ceil(ns as u64, FREQ))
+    }
+}
+
+impl<const FREQ: usize> DelayNs for FixedDelay<FREQ> {
+    #[inline]
+    fn delay_ns(&mut self, ns: u32) {
+        wait_ticks(self.mtime, self.ticks_for_ns(ns));
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl embedded_hal_02::blocking::delay::DelayUs<u32> for Delay {
+    #[inline]
+    fn delay_us(&mut self, us: u32) {
+        self.delay(Duration::from_micros(us.into()));
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl embedded_hal_02::blocking::delay::DelayMs<u32> for Delay {
+    #[inline]
+    fn delay_ms(&mut self, ms: u32) {
+        self.delay(Duration::from_millis(ms.into()));
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<const FREQ: usize> embedded_hal_02::blocking::delay::DelayUs<u32> for FixedDelay<FREQ> {
+    #[inline]
+    fn delay_us(&mut self, us: u32) {
+        self.delay(Duration::from_micros(us.into()));
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<const FREQ: usize> embedded_hal_02::blocking::delay::DelayMs<u32> for FixedDelay<FREQ> {
+    #[inline]
+    fn delay_ms(&mut self, ms: u32) {
+        self.delay(Duration::from_millis(ms.into()));
+    }
+}
+
+/// Returns the number of `MTIME` ticks to wait for so that at least `ticks` full periods elapse.
+///
+/// The wait starts at an arbitrary point within the current tick, so one more tick is needed.
+/// A zero-length wait returns immediately.
+#[inline]
+const fn wait_len(ticks: u64) -> u64 {
+    match ticks {
+        0 => 0,
+        _ => ticks.saturating_add(1),
+    }
+}
+
+/// Busy-waits until `n_ticks` ticks of the `MTIME` register have elapsed.
+#[inline]
+fn wait_ticks(mtime: MTIME, n_ticks: u64) {
+    #[cfg(test)]
+    if let Some(hook) = test::POLL_HOOK.with(|hook| hook.get()) {
+        return wait_ticks_with(mtime, n_ticks, || hook(mtime));
+    }
+    wait_ticks_with(mtime, n_ticks, core::hint::spin_loop);
+}
+
+/// Waits until `n_ticks` ticks of the `MTIME` register have elapsed, calling `hook` between polls.
+#[inline]
+fn wait_ticks_with<F: FnMut()>(mtime: MTIME, n_ticks: u64, mut hook: F) {
+    let t0 = mtime.read();
+    while mtime.read().wrapping_sub(t0) < n_ticks {
+        hook();
+    }
+}
+
+/// Delay implementation for (A)CLINT peripherals that yields between `MTIME` polls.
+///
+/// It behaves as [`Delay`], but instead of spinning, it calls a user-provided hook between
+/// polls until the deadline (e.g., [`riscv::asm::wfi`] with the machine timer interrupt armed,
+/// or the yield function of a cooperative scheduler). This reduces power consumption for long
+/// blocking delays. [`Delay`] is equivalent to a `YieldDelay` with [`core::hint::spin_loop`] as hook.
+///
+/// # Note
+///
+/// The delay lasts at least the requested time, but it may last longer if the hook does not return
+/// promptly (e.g., if it waits for an interrupt that is not armed, `wfi` may never return).
+#[derive(Clone, Copy, Debug)]
+pub struct YieldDelay<F> {
+    delay: Delay,
+    hook: F,
+}
+
+impl<F: FnMut()> YieldDelay<F> {
+    /// Creates a new `YieldDelay` instance.
+    #[inline]
+    pub const fn new(mtime: MTIME, freq: usize, hook: F) -> Self {
+        Delay::new(mtime, freq).with_yield(hook)
+    }
+
+    /// Returns the underlying [`Delay`].
+    #[inline]
+    pub const fn get_delay(&self) -> Delay {
+        self.delay
+    }
+
+    /// Blocks the current HART for at least the given [`Duration`], calling the hook between polls.
+    #[inline]
+    pub fn delay(&mut self, duration: Duration) {
+        let n_ticks = self.delay.duration_to_ticks(duration);
+        wait_ticks_with(self.delay.mtime, n_ticks, &mut self.hook);
+    }
+}
+
+impl<F: FnMut()> DelayNs for YieldDelay<F> {
+    #[inline]
+    fn delay_ns(&mut self, ns: u32) {
+        let n_ticks = self.delay.ticks_for_ns(ns);
+        wait_ticks_with(self.delay.mtime, n_ticks, &mut self.hook);
+    }
+}
+
+/// One-shot alarm for (A)CLINT peripherals.
+///
+/// An alarm arms the `MTIMECMP` register of a HART for an absolute `MTIME` deadline.
+/// It does **not** enable machine timer interrupts. It is up to the caller to either
+/// poll the alarm or enable interrupts to be notified when the deadline is reached.
+#[derive(Clone, Copy, Debug)]
+pub struct Alarm {
+    mtime: MTIME,
+    mtimecmp: MTIMECMP,
+    freq: usize,
+    callback: Option<fn()>,
+}
+
+impl Alarm {
+    /// Creates a new `Alarm` instance for an `MTIME` register running at `freq` Hz.
+    #[inline]
+    pub const fn new(mtime: MTIME, mtimecmp: MTIMECMP, freq: usize) -> Self {
+        Self {
+            mtime,
+            mtimecmp,
+            freq,
+            callback: None,
+        }
+    }
+
+    /// Returns the frequency of the `MTIME` register.
+    #[inline]
+    pub const fn get_freq(&self) -> usize {
+        self.freq
+    }
+
+    /// Sets the frequency of the `MTIME` register.
+    #[inline]
+    pub fn set_freq(&mut self, freq: usize) {
+        self.freq = freq;
+    }
+
+    /// Sets the callback to be invoked by [`Alarm::poll`] when the alarm expires.
+    #[inline]
+    pub fn set_callback(&mut self, callback: fn()) {
+        self.callback = Some(callback);
+    }
+
+    /// Removes the callback of the alarm.
+    #[inline]
+    pub fn clear_callback(&mut self) {
+        self.callback = None;
+    }
+
+    /// Arms the alarm to expire when `MTIME` reaches `deadline`.
+    #[inline]
+    pub fn arm(&self, deadline: u64) {
+        self.mtimecmp.write(deadline);
+    }
+
+    /// Disarms the alarm by setting the `MTIMECMP` register to `u64::MAX`.
+    #[inline]
+    pub fn disarm(&self) {
+        self.mtimecmp.write(u64::MAX);
+    }
+
+    /// Returns the deadline of the alarm.
+    #[inline]
+    pub fn deadline(&self) -> u64 {
+        self.mtimecmp.read()
+    }
+
+    /// Returns `true` if the alarm is armed.
+    #[inline]
+    pub fn is_armed(&self) -> bool {
+        self.deadline() != u64::MAX
+    }
+
+    /// Returns `true` if `MTIME` has reached the deadline of the alarm.
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.mtimecmp.is_due(self.mtime.read())
+    }
+
+    /// Returns the time left until the deadline of the alarm.
+    ///
+    /// It returns `None` if the alarm is not armed or has already expired.
+    #[inline]
+    pub fn remaining(&self) -> Option<Duration> {
+        let deadline = self.deadline();
+        let now = self.mtime.read();
+        if deadline == u64::MAX || now >= deadline {
+            return None;
+        }
+        Some(ticks_to_duration(deadline - now, self.freq))
+    }
+
+    /// Checks if the alarm has expired. If so, it disarms the alarm,
+    /// invokes the callback (if any), and returns `true`.
+    #[inline]
+    pub fn poll(&self) -> bool {
+        if self.is_expired() {
+            self.disarm();
+            if let Some(callback) = self.callback {
+                callback();
+            }
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    extern crate std;
+
+    std::thread_local! {
+        /// Function called by [`wait_ticks`] between `MTIME` polls instead of spinning, if set.
+        pub(super) static POLL_HOOK: core::cell::Cell<Option<fn(MTIME)>> = const { core::cell::Cell::new(None) };
+    }
+
+    #[test]
+    fn test_delay_duration() {
+        let raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(&raw_mtime as *const u64 as _) };
+        let mut delay = Delay::new(mtime, 32_768);
+
+        // rounded up, plus one tick for the phase of MTIME
+        assert_eq!(delay.duration_to_ticks(Duration::from_secs(10)), 327_681);
+        assert_eq!(delay.duration_to_ticks(Duration::from_millis(1)), 34);
+        assert_eq!(delay.duration_to_ticks(Duration::ZERO), 0);
+
+        delay.set_freq(1_000_000_000);
+        assert_eq!(
+            delay.duration_to_ticks(Duration::from_secs(10)),
+            10_000_000_001
+        );
+        assert_eq!(delay.duration_to_ticks(Duration::MAX), u64::MAX);
+
+        // a zero-length delay must return immediately, even if MTIME does not advance
+        delay.delay(Duration::ZERO);
+    }
+
+    #[test]
+    fn test_delay_ticks_for_ns() {
+        let raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(&raw_mtime as *const u64 as _) };
+        let mut delay = Delay::new(mtime, 32_768);
+
+        // (ns, ticks) pairs around tick boundaries: rounded up, plus one tick for the phase of MTIME
+        assert_eq!(delay.ticks_for_ns(0), 0);
+        assert_eq!(delay.ticks_for_ns(1), 2);
+        assert_eq!(delay.ticks_for_ns(30_517), 2);
+        assert_eq!(delay.ticks_for_ns(30_518), 3);
+        assert_eq!(delay.ticks_for_ns(u32::MAX), 140_739);
+
+        delay.set_freq(1_000_000);
+        assert_eq!(delay.ticks_for_ns(999), 2);
+        assert_eq!(delay.ticks_for_ns(1_000), 2);
+        assert_eq!(delay.ticks_for_ns(1_001), 3);
+        assert_eq!(delay.ticks_for_ns(u32::MAX), 4_294_969);
+
+        delay.set_freq(10_000_000);
+        assert_eq!(delay.ticks_for_ns(99), 2);
+        assert_eq!(delay.ticks_for_ns(100), 2);
+        assert_eq!(delay.ticks_for_ns(u32::MAX), 42_949_674);
+
+        delay.set_freq(1_000_000_000);
+        assert_eq!(delay.ticks_for_ns(1), 2);
+        assert_eq!(delay.ticks_for_ns(u32::MAX), u32::MAX as u64 + 1);
+
+        // a timer that never advances must not block forever
+        delay.set_freq(0);
+        assert_eq!(delay.ticks_for_ns(u32::MAX), 0);
+
+        // both forms produce the same number of ticks
+        assert_eq!(
+            FixedDelay::<32_768>::new(mtime).ticks_for_ns(30_518),
+            Delay::new(mtime, 32_768).ticks_for_ns(30_518)
+        );
+    }
+
+    #[test]
+    fn test_delay_at_least() {
+        use crate::hal::time::{ns_to_ticks, ticks_to_ns};
+
+        const FREQ: usize = 32_768;
+        let mut raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(core::ptr::addr_of_mut!(raw_mtime) as _) };
+
+        // the delay starts at different points within an MTIME tick (1 tick = 30_517.578125 ns)
+        for start in [0, 15_000, 30_517] {
+            for ns in [1, 30_517, 30_518, 61_036, 1_000_000] {
+                let ticks = Delay::new(mtime, FREQ).ticks_for_ns(ns);
+                let mut now = start;
+                mtime.write(ns_to_ticks(now, FREQ));
+                // the hook emulates the passage of time in steps of 100 ns
+                Delay::new(mtime, FREQ)
+                    .with_yield(|| {
+                        now += 100;
+                        mtime.write(ns_to_ticks(now, FREQ));
+                    })
+                    .delay_ns(ns);
+                let elapsed = now - start;
+                // never shorter than requested, and at most `ticks` MTIME periods
+                assert!(elapsed >= ns as u64, "{elapsed} < {ns}");
+                assert!(elapsed <= ticks_to_ns(ticks, FREQ) + 100);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_delay() {
+        let raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(&raw_mtime as *const u64 as _) };
+
+        let mut fixed = FixedDelay::<32_768>::new(mtime);
+        let runtime = Delay::from(fixed);
+        assert_eq!(fixed.get_freq(), 32_768);
+        assert_eq!(runtime.get_freq(), 32_768);
+        assert_eq!(runtime.get_mtime(), fixed.get_mtime());
+
+        // both forms produce the same number of ticks
+        for ns in [0, 1, 30_517, 30_518, 1_000_000, u32::MAX] {
+            assert_eq!(
+                fixed.ticks_for_ns(ns),
+                runtime.duration_to_ticks(Duration::from_nanos(ns.into()))
+            );
+        }
+        for duration in [
+            Duration::ZERO,
+            Duration::from_millis(1),
+            Duration::from_secs(10),
+            Duration::MAX,
+        ] {
+            assert_eq!(
+                wait_len(duration_to_ticks_ceil(duration, fixed.get_freq())),
+                runtime.duration_to_ticks(duration)
+            );
+        }
+
+        // zero-length delays must return immediately, even if MTIME does not advance
+        fixed.delay(Duration::ZERO);
+        fixed.delay_ns(0);
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[test]
+    fn test_delay_embedded_hal_02() {
+        use embedded_hal_02::blocking::delay::{DelayMs, DelayUs};
+
+        // drivers written against either embedded-hal version accept the same delay
+        fn legacy_driver<D: DelayUs<u32> + DelayMs<u32>>(delay: &mut D) {
+            delay.delay_us(0);
+            delay.delay_ms(0);
+        }
+        fn driver<D: DelayNs>(delay: &mut D) {
+            delay.delay_ns(0);
+        }
+
+        let mut raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(core::ptr::addr_of_mut!(raw_mtime) as _) };
+
+        // zero-length delays must return immediately, even if MTIME does not advance
+        let mut delay = Delay::new(mtime, 32_768);
+        legacy_driver(&mut delay);
+        driver(&mut delay);
+        let mut fixed = FixedDelay::<32_768>::new(mtime);
+        legacy_driver(&mut fixed);
+        driver(&mut fixed);
+        assert_eq!(mtime.read(), 0);
+
+        // MTIME advances one tick on every poll, so it counts the ticks waited by each delay
+        POLL_HOOK.with(|hook| hook.set(Some(|mtime| mtime.write(mtime.read() + 1))));
+        let waited = |f: &mut dyn FnMut()| {
+            let start = mtime.read();
+            f();
+            mtime.read() - start
+        };
+        for (us, ms) in [(1, 1), (30, 3), (1_000, 4)] {
+            let us_ticks = delay.ticks_for_ns(us * 1_000);
+            let ms_ticks = delay.ticks_for_ns(ms * 1_000_000);
+            assert_ne!(us_ticks, 0);
+            assert_eq!(waited(&mut || DelayUs::delay_us(&mut delay, us)), us_ticks);
+            assert_eq!(waited(&mut || DelayUs::delay_us(&mut fixed, us)), us_ticks);
+            assert_eq!(waited(&mut || DelayMs::delay_ms(&mut delay, ms)), ms_ticks);
+            assert_eq!(waited(&mut || DelayMs::delay_ms(&mut fixed, ms)), ms_ticks);
+        }
+        POLL_HOOK.with(|hook| hook.set(None));
+        assert_eq!(delay.ticks_for_ns(1_000_000), 34);
+    }
+
+    #[test]
+    fn test_delay_copy() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Delay>();
+
+        struct Driver<D: DelayNs> {
+            delay: D,
+        }
+
+        let raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(&raw_mtime as *const u64 as _) };
+        let delay = Delay::new(mtime, 32_768);
+
+        let mut driver1 = Driver { delay };
+        let mut driver2 = Driver { delay };
+        driver1.delay.delay_ns(0);
+        driver2.delay.delay_ns(0);
+        assert_eq!(driver1.delay, driver2.delay);
+    }
+
+    #[test]
+    fn test_delay_size() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<MTIME>(), size_of::<usize>());
+        assert_eq!(size_of::<Delay>(), size_of::<MTIME>() + size_of::<usize>());
+        assert_eq!(size_of::<FixedDelay<32_768>>(), size_of::<MTIME>());
+    }
+
+    #[test]
+    fn test_delay_multi_hart() {
+        extern crate std;
+
+        let raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(&raw_mtime as *const u64 as _) };
+        let delay = Delay::new(mtime, 32_768);
+
+        // several HARTs (emulated with threads) use the same delay instance through a shared reference
+        std::thread::scope(|s| {
+            for _ in 0..3 {
+                let delay = &delay;
+                s.spawn(move || {
+                    assert_eq!(delay.duration_to_ticks(Duration::from_millis(1)), 34);
+                    let mut delay = *delay;
+                    delay.delay(Duration::ZERO);
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn test_yield_delay() {
+        let mut raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(core::ptr::addr_of_mut!(raw_mtime) as _) };
+
+        // the hook emulates the passage of time while the HART yields
+        let mut yields = 0;
+        let mut delay = YieldDelay::new(mtime, 32_768, || {
+            yields += 1;
+            mtime.write(mtime.read() + 1);
+        });
+        delay.delay(Duration::from_millis(1)); // 34 ticks
+        delay.delay_ns(30_518); // 3 ticks
+        delay.delay(Duration::ZERO);
+        assert_eq!(yields, 37);
+        assert_eq!(mtime.read(), 37);
+
+        let mut yields = 0;
+        let mut delay = Delay::new(mtime, 1_000).with_yield(|| {
+            yields += 1;
+            mtime.write(mtime.read() + 250);
+        });
+        assert_eq!(delay.get_delay().get_freq(), 1_000);
+        delay.delay_ms(1);
+        assert!(yields >= 1);
+    }
+
+    #[test]
+    fn test_delay_calibrate() {
+        let mut raw_mtime = 0u64;
+        // SAFETY: valid memory address
+        let mtime = unsafe { MTIME::new(core::ptr::addr_of_mut!(raw_mtime) as _) };
+
+        // CPU at 100 MHz and MTIME at 32.768 kHz: MTIME advances every ~3052 cycles
+        let mut cycles = 0;
+        let read_cycles = || {
+            cycles += 1_000;
+            mtime.write(cycles * 32_768 / 100_000_000);
+            cycles
+        };
+        let freq = Delay::calibrate_with(mtime, 100_000_000, 100_000_000, read_cycles);
+        assert_eq!(freq, 32_768);
+
+        // a zero-length calibration must not divide by zero
+        assert_eq!(Delay::calibrate_with(mtime, 100_000_000, 0, || 0), 0);
+    }
+
+    #[test]
+    fn test_alarm() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static FIRED: AtomicUsize = AtomicUsize::new(0);
+
+        let mut raw_mtime = 0u64;
+        let mut raw_mtimecmp = 0u64;
+        // SAFETY: valid memory addresses
+        let (mtime, mtimecmp) = unsafe {
+            (
+                MTIME::new(core::ptr::addr_of_mut!(raw_mtime) as _),
+                MTIMECMP::new(core::ptr::addr_of_mut!(raw_mtimecmp) as _),
+            )
+        };
+        let mut alarm = Alarm::new(mtime, mtimecmp, 32_768);
+        alarm.set_callback(|| {
+            FIRED.fetch_add(1, Ordering::SeqCst);
+        });
+
+        alarm.disarm();
+        assert!(!alarm.is_armed());
+        assert!(!alarm.is_expired());
+        assert!(!alarm.poll());
+
+        alarm.arm(100);
+        assert!(alarm.is_armed());
+        assert_eq!(alarm.deadline(), 100);
+
+        for (t, expired) in [(0, false), (99, false), (100, true), (150, true)] {
+            mtime.write(t);
+            assert_eq!(alarm.is_expired(), expired);
+        }
+
+        assert!(alarm.poll());
+        assert!(!alarm.is_armed());
+        assert_eq!(FIRED.load(Ordering::SeqCst), 1);
+
+        // the alarm is one-shot
+        assert!(!alarm.poll());
+        assert_eq!(FIRED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_alarm_remaining() {
+        let mut raw_mtime = 0u64;
+        let mut raw_mtimecmp = 0u64;
+        // SAFETY: valid memory addresses
+        let (mtime, mtimecmp) = unsafe {
+            (
+                MTIME::new(core::ptr::addr_of_mut!(raw_mtime) as _),
+                MTIMECMP::new(core::ptr::addr_of_mut!(raw_mtimecmp) as _),
+            )
+        };
+        let alarm = Alarm::new(mtime, mtimecmp, 1_000);
+
+        alarm.disarm();
+        assert_eq!(alarm.remaining(), None);
+
+        alarm.arm(2_500);
+        for (t, remaining) in [
+            (0, Some(Duration::from_millis(2_500))),
+            (1_000, Some(Duration::from_millis(1_500))),
+            (2_499, Some(Duration::from_millis(1))),
+            (2_500, None),
+            (3_000, None),
+        ] {
+            mtime.write(t);
+            assert_eq!(alarm.remaining(), remaining);
+        }
+
+        // sub-tick resolution is truncated to nanoseconds
+        let mut alarm = alarm;
+        alarm.set_freq(32_768);
+        mtime.write(0);
+        alarm.arm(32_769);
+        assert_eq!(
+            alarm.remaining(),
+            Some(Duration::new(1, 30_517)) // 1 / 32_768 s = 30_517.578125 ns
+        );
     }
 }
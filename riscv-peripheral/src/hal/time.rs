@@ -0,0 +1,230 @@
+//! Conversions between time units and timer ticks.
+//!
+//! These functions are shared by all the timer types of this crate.
+//! They use 128-bit intermediates, saturate at the maximum value of the output type,
+//! and never divide by zero. For a timer running at `freq` Hz, a frequency of zero
+//! means that the timer never advances.
+
+use core::time::Duration;
+
+/// Number of nanoseconds in a second.
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Converts a 128-bit value to `u64`, saturating at `u64::MAX`.
+#[inline(always)]
+const fn saturate(value: u128) -> u64 {
+    if value > u64::MAX as u128 {
+        u64::MAX
+    } else {
+        value as u64
+    }
+}
+
+/// Converts `ns` nanoseconds to ticks of a timer running at `freq` Hz.
+///
+/// The result is truncated towards zero and saturates at `u64::MAX`.
+#[inline]
+pub const fn ns_to_ticks(ns: u64, freq: usize) -> u64 {
+    saturate(ns as u128 * freq as u128 / NANOS_PER_SEC)
+}
+
+/// Converts a [`Duration`] to ticks of a timer running at `freq` Hz.
+///
+/// The result is truncated towards zero and saturates at `u64::MAX`.
+#[inline]
+pub const fn duration_to_ticks(duration: Duration, freq: usize) -> u64 {
+    match duration.as_nanos().checked_mul(freq as u128) {
+        Some(n) => saturate(n / NANOS_PER_SEC),
+        None => u64::MAX,
+    }
+}
+
+/// Converts `ns` nanoseconds to ticks of a timer running at `freq` Hz.
+///
+/// The result is rounded up (i.e., the ticks last at least `ns` nanoseconds) and saturates at `u64::MAX`.
+#[inline]
+pub const fn ns_to_ticks_ceil(ns: u64, freq: usize) -> u64 {
+    saturate((ns as u128 * freq as u128).div_ceil(NANOS_PER_SEC))
+}
+
+/// Converts a [`Duration`] to ticks of a timer running at `freq` Hz.
+///
+/// The result is rounded up (i.e., the ticks last at least `duration`) and saturates at `u64::MAX`.
+#[inline]
+pub const fn duration_to_ticks_ceil(duration: Duration, freq: usize) -> u64 {
+    match duration.as_nanos().checked_mul(freq as u128) {
+        Some(n) => saturate(n.div_ceil(NANOS_PER_SEC)),
+        None => u64::MAX,
+    }
+}
+
+/// Converts `ticks` ticks of a timer running at `freq` Hz to nanoseconds.
+///
+/// The result is truncated towards zero and saturates at `u64::MAX`.
+/// If `freq` is zero, any non-zero number of ticks saturates.
+#[inline]
+pub const fn ticks_to_ns(ticks: u64, freq: usize) -> u64 {
+    match (ticks, freq) {
+        (0, _) => 0,
+        (_, 0) => u64::MAX,
+        _ => saturate(ticks as u128 * NANOS_PER_SEC / freq as u128),
+    }
+}
+
+/// Converts `ticks` ticks of a timer running at `freq` Hz to a [`Duration`].
+///
+/// The result is truncated towards zero to nanosecond resolution.
+/// If `freq` is zero, any non-zero number of ticks saturates at [`Duration::MAX`].
+#[inline]
+pub const fn ticks_to_duration(ticks: u64, freq: usize) -> Duration {
+    match (ticks, freq) {
+        (0, _) => Duration::ZERO,
+        (_, 0) => Duration::MAX,
+        _ => {
+            let (ticks, freq) = (ticks as u128, freq as u128);
+            let secs = ticks / freq;
+            let nanos = (ticks % freq) * NANOS_PER_SEC / freq;
+            Duration::new(secs as u64, nanos as u32)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ns_to_ticks() {
+        // (ns, ticks) pairs around tick boundaries
+        assert_eq!(ns_to_ticks(0, 32_768), 0);
+        assert_eq!(ns_to_ticks(30_517, 32_768), 0);
+        assert_eq!(ns_to_ticks(30_518, 32_768), 1);
+        assert_eq!(ns_to_ticks(u32::MAX as u64, 32_768), 140_737);
+        assert_eq!(ns_to_ticks(999, 1_000_000), 0);
+        assert_eq!(ns_to_ticks(1_000, 1_000_000), 1);
+        assert_eq!(ns_to_ticks(u64::MAX, 1_000_000_000), u64::MAX);
+
+        // the intermediate product does not overflow 64 bits
+        assert_eq!(ns_to_ticks(u64::MAX, 1_000), 18_446_744_073_709);
+        // saturation
+        assert_eq!(ns_to_ticks(u64::MAX, usize::MAX), u64::MAX);
+        // a timer that never advances
+        assert_eq!(ns_to_ticks(u64::MAX, 0), 0);
+    }
+
+    #[test]
+    fn test_duration_to_ticks() {
+        assert_eq!(duration_to_ticks(Duration::ZERO, 32_768), 0);
+        assert_eq!(duration_to_ticks(Duration::from_millis(1), 32_768), 32);
+        assert_eq!(duration_to_ticks(Duration::from_secs(10), 32_768), 327_680);
+        assert_eq!(
+            duration_to_ticks(Duration::from_secs(10), 1_000_000_000),
+            10_000_000_000
+        );
+
+        // saturation
+        assert_eq!(duration_to_ticks(Duration::MAX, 1), u64::MAX);
+        assert_eq!(duration_to_ticks(Duration::MAX, usize::MAX), u64::MAX);
+        // a timer that never advances
+        assert_eq!(duration_to_ticks(Duration::MAX, 0), 0);
+
+        // consistent with ns_to_ticks
+        for ns in [0, 1, 30_517, 30_518, 1_000_000, u32::MAX as u64, u64::MAX] {
+            for freq in [1, 1_000, 32_768, 1_000_000_000, usize::MAX] {
+                assert_eq!(
+                    duration_to_ticks(Duration::from_nanos(ns), freq),
+                    ns_to_ticks(ns, freq)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ticks_ceil() {
+        assert_eq!(ns_to_ticks_ceil(0, 32_768), 0);
+        assert_eq!(ns_to_ticks_ceil(1, 32_768), 1);
+        // 1 / 32_768 s = 30_517.578125 ns
+        assert_eq!(ns_to_ticks_ceil(30_517, 32_768), 1);
+        assert_eq!(ns_to_ticks_ceil(30_518, 32_768), 2);
+        assert_eq!(ns_to_ticks_ceil(1_000, 1_000_000), 1);
+        assert_eq!(ns_to_ticks_ceil(1_001, 1_000_000), 2);
+        assert_eq!(duration_to_ticks_ceil(Duration::from_millis(1), 32_768), 33);
+        assert_eq!(
+            duration_to_ticks_ceil(Duration::from_secs(10), 32_768),
+            327_680
+        );
+
+        // saturation
+        assert_eq!(ns_to_ticks_ceil(u64::MAX, usize::MAX), u64::MAX);
+        assert_eq!(duration_to_ticks_ceil(Duration::MAX, 1), u64::MAX);
+        // a timer that never advances
+        assert_eq!(ns_to_ticks_ceil(u64::MAX, 0), 0);
+        assert_eq!(duration_to_ticks_ceil(Duration::MAX, 0), 0);
+
+        // the rounded-up ticks last at least the requested time, and at most one tick more
+        for ns in [0, 1, 30_517, 30_518, 1_000_000, u32::MAX as u64] {
+            for freq in [1, 1_000, 32_768, 1_000_000_000] {
+                let ticks = ns_to_ticks_ceil(ns, freq);
+                assert_eq!(
+                    ticks,
+                    duration_to_ticks_ceil(Duration::from_nanos(ns), freq)
+                );
+                assert!(ticks - ns_to_ticks(ns, freq) <= 1);
+                assert!(ticks as u128 * NANOS_PER_SEC >= ns as u128 * freq as u128);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ticks_to_ns() {
+        assert_eq!(ticks_to_ns(0, 32_768), 0);
+        assert_eq!(ticks_to_ns(1, 32_768), 30_517);
+        assert_eq!(ticks_to_ns(32_768, 32_768), 1_000_000_000);
+        assert_eq!(ticks_to_ns(1, 1_000_000_000), 1);
+        assert_eq!(ticks_to_ns(u64::MAX, usize::MAX), 1_000_000_000);
+
+        // the intermediate product does not overflow 64 bits
+        assert_eq!(ticks_to_ns(u64::MAX, 1_000_000_000), u64::MAX);
+        assert_eq!(ticks_to_ns(1 << 40, 1 << 20), 1_000_000_000 << 20);
+        // saturation
+        assert_eq!(ticks_to_ns(u64::MAX, 1), u64::MAX);
+        // a timer that never advances
+        assert_eq!(ticks_to_ns(0, 0), 0);
+        assert_eq!(ticks_to_ns(1, 0), u64::MAX);
+
+        // round trip never overshoots
+        for ticks in [0, 1, 2, 1_000, 32_767, 32_768, 1 << 32] {
+            for freq in [1_000, 32_768, 1_000_000_000] {
+                assert!(ns_to_ticks(ticks_to_ns(ticks, freq), freq) <= ticks);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ticks_to_duration() {
+        assert_eq!(ticks_to_duration(0, 32_768), Duration::ZERO);
+        assert_eq!(
+            ticks_to_duration(1_500, 1_000),
+            Duration::from_millis(1_500)
+        );
+        // 1 / 32_768 s = 30_517.578125 ns
+        assert_eq!(ticks_to_duration(32_769, 32_768), Duration::new(1, 30_517));
+        assert_eq!(
+            ticks_to_duration(u64::MAX, 1),
+            Duration::from_secs(u64::MAX)
+        );
+        // a timer that never advances
+        assert_eq!(ticks_to_duration(0, 0), Duration::ZERO);
+        assert_eq!(ticks_to_duration(1, 0), Duration::MAX);
+
+        // consistent with ticks_to_ns when it does not saturate
+        for ticks in [0, 1, 1_000, 32_769, 1 << 32] {
+            for freq in [1_000, 32_768, 1_000_000_000] {
+                assert_eq!(
+                    ticks_to_duration(ticks, freq).as_nanos(),
+                    ticks_to_ns(ticks, freq) as u128
+                );
+            }
+        }
+    }
+}
@@ -19,10 +19,12 @@
 //! The function returns the next [`MTIME`] tick at which the next timer expires. If the queue is empty, it returns `None`.
 
 use crate::aclint::mtimer::{MTIME, MTIMECMP, MTIMER};
+use crate::hal::time::ns_to_ticks;
 pub use crate::hal_async::delay::DelayNs;
 use core::{
     cmp::{Eq, Ord, PartialEq, PartialOrd},
     future::Future,
+    marker::PhantomData,
     pin::Pin,
     task::{Context, Poll, Waker},
 };
@@ -86,11 +88,20 @@ fn schedule_machine_timer(mtime: MTIME, mtimercmp: MTIMECMP) {
 /// Therefore, it needs to schedule the machine-level timer interrupts via the [`MTIMECMP`] register assigned to the current HART.
 /// Thus, the [`Delay`] instance must be created on the same HART that is used to call the asynchronous delay methods.
 /// Additionally, the rest of the application must not modify the [`MTIMER`] register assigned to the current HART.
+///
+/// For this reason, unlike [`crate::hal::aclint::Delay`], this type is neither `Send` nor `Sync`:
+/// moving it to another HART would make it schedule interrupts in the `MTIMECMP` register of the wrong HART.
+///
+/// ```compile_fail
+/// fn assert_send<T: Send>() {}
+/// assert_send::<riscv_peripheral::hal_async::aclint::Delay>();
+/// ```
 #[derive(Clone)]
 pub struct Delay {
     freq: usize,
     mtime: MTIME,
     mtimecmp: MTIMECMP,
+    _marker: PhantomData<*const ()>, // MTIMECMP is bound to the HART that created the instance
 }
 
 impl Delay {
@@ -103,6 +114,7 @@ impl Delay {
             freq,
             mtime,
             mtimecmp,
+            _marker: PhantomData,
         }
     }
 
@@ -122,19 +134,19 @@ impl Delay {
 impl DelayNs for Delay {
     #[inline]
     async fn delay_ns(&mut self, ns: u32) {
-        let n_ticks = ns as u64 * self.get_freq() as u64 / 1_000_000_000;
+        let n_ticks = ns_to_ticks(ns as u64, self.get_freq());
         DelayAsync::new(self, n_ticks).await;
     }
 
     #[inline]
     async fn delay_us(&mut self, us: u32) {
-        let n_ticks = us as u64 * self.get_freq() as u64 / 1_000_000;
+        let n_ticks = ns_to_ticks(us as u64 * 1_000, self.get_freq());
         DelayAsync::new(self, n_ticks).await;
     }
 
     #[inline]
     async fn delay_ms(&mut self, ms: u32) {
-        let n_ticks = ms as u64 * self.get_freq() as u64 / 1_000;
+        let n_ticks = ns_to_ticks(ms as u64 * 1_000_000, self.get_freq());
         DelayAsync::new(self, n_ticks).await;
     }
 }
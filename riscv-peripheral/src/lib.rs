@@ -4,13 +4,26 @@
 //!
 //! - `aclint-hal-async`: enables the [`hal_async::delay::DelayNs`] implementation for the ACLINT peripheral.
 //! This feature relies on external functions that must be provided by the user. See [`hal_async::aclint`] for more information.
+//! - `critical-section`: enables the [`guarded::Guarded`] wrapper, which runs PLIC and CLINT configuration methods
+//! inside a critical section. The target must provide a `critical-section` implementation.
+//! - `embedded-hal-02`: additionally implements the legacy `embedded-hal` 0.2 `DelayUs<u32>` and `DelayMs<u32>`
+//! traits for [`hal::aclint::Delay`] and [`hal::aclint::FixedDelay`].
+//! - `test-mock`: enables overriding the base address of the PLIC peripheral at runtime for testing on the host.
+//! This feature requires the standard library. See [`plic::PLIC::set_mock_base`] for more information.
+//! - `zacas`: uses the `amocas.w`/`amocas.d` instructions of the Zacas extension in [`common::Reg`]'s `atomic_compare_exchange`.
+//! Without it, compare-and-swap falls back to an `lr`/`sc` loop. Only enable it if the target implements Zacas.
 
 #![deny(missing_docs)]
 #![no_std]
 
+#[cfg(feature = "test-mock")]
+extern crate std;
+
 pub use riscv; // re-export riscv crate to allow macros to use it
 
 pub mod common; // common definitions for all peripherals
+#[cfg(feature = "critical-section")]
+pub mod guarded; // critical-section protected access to PLIC and CLINT registers
 pub mod hal; // trait implementations for embedded-hal
 #[cfg(feature = "embedded-hal-async")]
 pub mod hal_async; // async trait implementations for embedded-hal
@@ -3,10 +3,12 @@
 /// Macro to create interfaces to CLINT peripherals in PACs.
 /// The resulting struct will be named `CLINT`, and will provide safe access to the CLINT registers.
 ///
-/// This macro expects 4 different argument types:
+/// This macro expects 5 different argument types:
 ///
 /// - Base address (**MANDATORY**): base address of the CLINT peripheral of the target.
 /// - Frequency (**OPTIONAL**): clock frequency (in Hz) of the `MTIME` register. It enables the `delay` method of the `CLINT` struct.
+/// - Per-HART frequencies (**OPTIONAL**): a list of clock frequencies (in Hz) of the `MTIME` register, indexed by HART ID.
+/// It enables the `delay_for_hart` method of the `CLINT` struct for targets with heterogeneous timebase frequencies.
 /// - Per-HART mtimecmp registers (**OPTIONAL**): a list of `mtimecmp` registers for easing access to per-HART mtimecmp regs.
 /// - Per-HART msip registers (**OPTIONAL**): a list of `msip` registers for easing access to per-HART msip regs.
 ///
@@ -24,6 +26,7 @@
 /// let mswi = CLINT::mswi(); // MSWI peripheral
 /// let mtimer = CLINT::mtimer(); // MTIMER peripheral
 /// let delay = CLINT::delay(); // For the `embedded_hal::delay::DelayNs` trait
+/// let fixed_delay = CLINT::fixed_delay(); // Same as `delay`, but with the frequency in the type
 /// ```
 ///
 /// ## Base address and per-HART mtimecmp registers
@@ -67,6 +70,51 @@
 /// let msip1 = CLINT::msip1(); // msip register for HART 1
 /// let msip2 = CLINT::msip2(); // msip register for HART 2
 /// ```
+///
+/// ## Base address and per-HART frequencies
+///
+/// ```
+/// use riscv_peripheral::clint_codegen;
+///
+/// /// HART IDs for the target CLINT peripheral
+/// #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// #[repr(u16)]
+/// pub enum HartId { H0 = 0, H1 = 1 }
+///
+/// // Implement `HartIdNumber` for `HartId`
+/// unsafe impl riscv_peripheral::aclint::HartIdNumber for HartId {
+///   const MAX_HART_ID_NUMBER: u16 = 1;
+///   fn number(self) -> u16 { self as _ }
+///   fn from_number(number: u16) -> Result<Self, u16> {
+///     if number > Self::MAX_HART_ID_NUMBER {
+///        Err(number)
+///     } else {
+///        // SAFETY: valid context number
+///        Ok(unsafe { core::mem::transmute(number) })
+///     }
+///   }
+/// }
+///
+/// clint_codegen!(base 0x0200_0000, freqs [32_768, 1_000_000],); // do not forget the ending comma!
+///
+/// let delay0 = CLINT::delay_for_hart(HartId::H0); // For the `embedded_hal::delay::DelayNs` trait
+/// let delay1 = CLINT::delay_for_hart(HartId::H1);
+///
+/// assert_eq!(delay0.get_freq(), 32_768);
+/// assert_eq!(delay1.get_freq(), 1_000_000);
+/// ```
+///
+/// ## One-call setup for timekeeping
+///
+/// ```no_run
+/// use riscv_peripheral::{clint_codegen, hal::delay::DelayNs};
+///
+/// clint_codegen!(base 0x0200_0000,);
+///
+/// // Machine timer interrupts are left disabled
+/// let mut delay = CLINT::setup(32_768);
+/// delay.delay_ms(100);
+/// ```
 #[macro_export]
 macro_rules! clint_codegen {
     () => {
@@ -190,6 +238,23 @@ macro_rules! clint_codegen {
             pub const fn mtime() -> $crate::aclint::mtimer::MTIME {
                 Self::mtimer().mtime
             }
+
+            /// Sets up the `MTIMER` peripheral for timekeeping and returns a delay implementation
+            /// for an `MTIME` register running at `freq` Hz.
+            ///
+            /// It disables machine timer interrupts and disarms the `MTIMECMP` register of the current HART.
+            /// Use the fine-grained methods of the `CLINT` struct to enable interrupts afterwards.
+            ///
+            /// # Note
+            ///
+            /// This function determines the current HART ID by reading the `mhartid` CSR.
+            /// Thus, it can only be used in M-mode.
+            #[inline]
+            pub fn setup(freq: usize) -> $crate::hal::aclint::Delay {
+                Self::mtimer_disable();
+                Self::mtimer().mtimecmp_mhartid().write(u64::MAX);
+                $crate::hal::aclint::Delay::new(Self::mtime(), freq)
+            }
         }
         $crate::clint_codegen!($($tail)*);
     };
@@ -210,6 +275,41 @@ macro_rules! clint_codegen {
             pub const fn delay() -> $crate::hal::aclint::Delay {
                 $crate::hal::aclint::Delay::new(Self::mtime(), Self::freq())
             }
+
+            /// Delay implementation for CLINT peripherals with the frequency known at compile time.
+            ///
+            /// # Note
+            ///
+            /// You must export the `riscv_peripheral::hal::delay::DelayNs` trait in order to use delay methods.
+            #[inline]
+            pub const fn fixed_delay() -> $crate::hal::aclint::FixedDelay<$freq> {
+                $crate::hal::aclint::FixedDelay::new(Self::mtime())
+            }
+        }
+        $crate::clint_codegen!($($tail)*);
+    };
+    (freqs [$($freq:literal),+], $($tail:tt)*) => {
+        impl CLINT {
+            /// Returns the frequency of the `MTIME` register for the HART which ID is `hart_id`.
+            ///
+            /// # Panics
+            ///
+            /// If no frequency has been provided for `hart_id`.
+            #[inline]
+            pub fn freq_for_hart<H: $crate::aclint::HartIdNumber>(hart_id: H) -> usize {
+                const FREQS: &[usize] = &[$($freq),+];
+                FREQS[hart_id.number() as usize]
+            }
+
+            /// Delay implementation for CLINT peripherals using the frequency of the HART which ID is `hart_id`.
+            ///
+            /// # Note
+            ///
+            /// You must export the `riscv_peripheral::hal::delay::DelayNs` trait in order to use delay methods.
+            #[inline]
+            pub fn delay_for_hart<H: $crate::aclint::HartIdNumber>(hart_id: H) -> $crate::hal::aclint::Delay {
+                $crate::hal::aclint::Delay::new(Self::mtime(), Self::freq_for_hart(hart_id))
+            }
         }
         $crate::clint_codegen!($($tail)*);
     };
@@ -263,13 +363,45 @@ macro_rules! clint_codegen {
 }
 
 /// Macro to create interfaces to PLIC peripherals in PACs.
+/// The resulting struct will be named `PLIC`, and will provide safe access to the PLIC registers.
+///
+/// This macro expects 3 different argument types:
+///
+/// - Base address (**MANDATORY**): base address of the PLIC peripheral of the target.
+/// - Register layout (**OPTIONAL**): a list of [`Plic`](crate::plic::Plic) layout constants to override.
+/// It must immediately follow the base address. Non-overridden constants keep the standard layout.
+/// - Per-HART contexts (**OPTIONAL**): a list of `ctx` methods for easing access to per-HART contexts.
+///
+/// # Example
+///
+/// ## Standard register layout
+///
+/// ```
+/// use riscv_peripheral::plic_codegen;
+///
+/// plic_codegen!(base 0x0C00_0000,); // do not forget the ending comma!
+///
+/// let priorities = PLIC::priorities(); // Priorities registers
+/// let pendings = PLIC::pendings(); // Pendings registers
+/// ```
+///
+/// ## Non-standard register layout
+///
+/// ```
+/// use riscv_peripheral::plic_codegen;
+///
+/// plic_codegen!(
+///     base 0x0C00_0000,
+///     layout [ENABLES_SEPARATION = 0x100, THRESHOLDS_SEPARATION = 0x2000, CLAIMS_SEPARATION = 0x2000],
+/// );
+/// ```
 #[macro_export]
 macro_rules! plic_codegen {
     () => {
         #[allow(unused_imports)]
         use PLIC as _; // assert that the PLIC struct is defined
     };
-    (base $addr:literal, $($tail:tt)*) => {
+    (base $addr:literal, layout [$($name:ident = $value:expr),*], $($tail:tt)*) => {
         /// PLIC peripheral
         #[allow(clippy::upper_case_acronyms)]
         #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -277,6 +409,7 @@ macro_rules! plic_codegen {
 
         unsafe impl $crate::plic::Plic for PLIC {
             const BASE: usize = $addr;
+            $(const $name: usize = $value;)*
         }
 
         impl PLIC {
@@ -309,6 +442,12 @@ macro_rules! plic_codegen {
                 unsafe { $crate::riscv::register::mie::clear_mext() };
             }
 
+            /// Maps a raw interrupt source number to the typed interrupt source.
+            #[inline]
+            pub fn source_from_number<I: $crate::plic::InterruptNumber>(number: u16) -> Result<I, u16> {
+                $crate::plic::PLIC::<PLIC>::source_from_number(number)
+            }
+
             /// Returns the priorities register of the PLIC.
             #[inline]
             pub fn priorities() -> $crate::plic::priorities::PRIORITIES {
@@ -327,6 +466,14 @@ macro_rules! plic_codegen {
                 $crate::plic::PLIC::<PLIC>::ctx(hart_id)
             }
 
+            /// Returns the context proxy of the PLIC context with raw index `index`.
+            ///
+            /// It returns `Err(index)` if `index` is out of range.
+            #[inline]
+            pub fn ctx_raw(index: usize) -> Result<$crate::plic::CTX<Self>, usize> {
+                $crate::plic::PLIC::<PLIC>::ctx_raw(index)
+            }
+
             /// Returns the PLIC HART context for the current HART.
             ///
             /// # Note
@@ -337,9 +484,20 @@ macro_rules! plic_codegen {
             pub fn ctx_mhartid(&self) -> $crate::plic::CTX<Self> {
                 $crate::plic::PLIC::<PLIC>::ctx_mhartid()
             }
+
+            /// Returns the PLIC HART context for the current HART, checking that it is a valid context.
+            ///
+            /// It returns `Err(hart_id)` if the current HART ID does not correspond to any context of type `H`.
+            #[inline]
+            pub fn try_ctx_mhartid<H: $crate::plic::HartIdNumber>() -> Result<$crate::plic::CTX<Self>, usize> {
+                $crate::plic::PLIC::<PLIC>::try_ctx_mhartid::<H>()
+            }
         }
         $crate::plic_codegen!($($tail)*);
     };
+    (base $addr:literal, $($tail:tt)*) => {
+        $crate::plic_codegen!(base $addr, layout [], $($tail)*);
+    };
     (ctxs [$($fn:ident = ($ctx:expr , $sctx:expr)),+], $($tail:tt)*) => {
         impl PLIC {
             $(
@@ -355,3 +513,139 @@ macro_rules! plic_codegen {
         $crate::plic_codegen!($($tail)*);
     };
 }
+
+/// Macro to create a machine-level interrupt handler that dispatches interrupts to user-defined functions.
+/// The resulting function is `#[no_mangle]` and has the name given as first argument.
+///
+/// The generated function reads the [`riscv::register::mcause`] CSR to determine the source of the interrupt.
+/// Then, it dispatches the interrupt according to the following arguments, which must be provided in this order:
+///
+/// - Software interrupt handler (**OPTIONAL**): function called on machine software interrupts.
+/// The handler is responsible for clearing the `MSIP` register of the current HART.
+/// - Timer interrupt handler (**OPTIONAL**): function called on machine timer interrupts.
+/// The handler is responsible for updating the `MTIMECMP` register of the current HART.
+/// - External interrupt handler (**OPTIONAL**): a PLIC type and a function that receives a claimed source.
+/// The generated function claims all the pending sources of the current HART context, calling the handler
+/// once per source and completing each source after its handler returns.
+/// - Default handler (**MANDATORY**): function called on any other trap.
+///
+/// # Note
+///
+/// When using `riscv-rt`, name the function `DefaultHandler`.
+/// As `MachineSoft`, `MachineTimer`, and `MachineExternal` default to `DefaultHandler`,
+/// the generated function receives all the machine-level interrupts.
+///
+/// # Example
+///
+/// ```no_run
+/// use riscv_peripheral::{interrupt_handler, plic::InterruptNumber, plic_codegen};
+///
+/// #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// #[repr(u16)]
+/// pub enum Interrupt { UART0 = 1, GPIO0 = 2 }
+///
+/// // Implement `InterruptNumber` for `Interrupt`
+/// unsafe impl InterruptNumber for Interrupt {
+///   const MAX_INTERRUPT_NUMBER: u16 = 2;
+///   fn number(self) -> u16 { self as _ }
+///   fn from_number(number: u16) -> Result<Self, u16> {
+///     if number > Self::MAX_INTERRUPT_NUMBER || number == 0 {
+///        Err(number)
+///     } else {
+///        // SAFETY: valid interrupt number
+///        Ok(unsafe { core::mem::transmute(number) })
+///     }
+///   }
+/// }
+///
+/// plic_codegen!(base 0x0C00_0000,);
+///
+/// fn timer_handler() {
+///     // update the MTIMECMP register...
+/// }
+///
+/// fn external_handler(source: Interrupt) {
+///     match source {
+///         Interrupt::UART0 => { /* ... */ }
+///         Interrupt::GPIO0 => { /* ... */ }
+///     }
+/// }
+///
+/// fn default_handler() {
+///     loop {}
+/// }
+///
+/// interrupt_handler!(
+///     DefaultHandler,
+///     timer timer_handler,
+///     external PLIC => external_handler,
+///     default default_handler,
+/// );
+/// ```
+#[macro_export]
+macro_rules! interrupt_handler {
+    (
+        $handler:ident,
+        $(software $soft:path,)?
+        $(timer $timer:path,)?
+        $(external $plic:ty => $ext:path,)?
+        default $default:path $(,)?
+    ) => {
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        fn $handler() {
+            match $crate::riscv::register::mcause::read().cause() {
+                $(
+                    $crate::riscv::register::mcause::Trap::Interrupt(
+                        $crate::riscv::register::mcause::Interrupt::MachineSoft,
+                    ) => $soft(),
+                )?
+                $(
+                    $crate::riscv::register::mcause::Trap::Interrupt(
+                        $crate::riscv::register::mcause::Interrupt::MachineTimer,
+                    ) => $timer(),
+                )?
+                $(
+                    $crate::riscv::register::mcause::Trap::Interrupt(
+                        $crate::riscv::register::mcause::Interrupt::MachineExternal,
+                    ) => {
+                        let claim = $crate::plic::PLIC::<$plic>::ctx_mhartid().claim();
+                        while let Some(source) = claim.claim() {
+                            $ext(source);
+                            claim.complete(source);
+                        }
+                    }
+                )?
+                _ => $default(),
+            }
+        }
+    };
+}
+
+/// Macro to check if any of the given peripherals is interrupting.
+///
+/// It expands to a boolean expression that calls the `is_interrupting` method of each peripheral
+/// generated by the [`clint_codegen!`] and [`plic_codegen!`] macros, and returns `true` if any of them is interrupting.
+/// Any number of peripherals can be provided. Thus, targets with only a CLINT or a PLIC can use it as well.
+///
+/// # Example
+///
+/// ```no_run
+/// use riscv_peripheral::{clint_codegen, is_interrupting, plic_codegen};
+///
+/// clint_codegen!(base 0x0200_0000,);
+/// plic_codegen!(base 0x0C00_0000,);
+///
+/// if is_interrupting!(CLINT, PLIC) {
+///     // attend the pending interrupts...
+/// }
+///
+/// // it also works with a single peripheral
+/// let clint_pending = is_interrupting!(CLINT);
+/// ```
+#[macro_export]
+macro_rules! is_interrupting {
+    ($($peripheral:ident),+ $(,)?) => {
+        false $(|| $peripheral::is_interrupting())+
+    };
+}
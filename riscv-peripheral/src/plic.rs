@@ -6,6 +6,7 @@ pub mod claim;
 pub mod enables;
 pub mod pendings;
 pub mod priorities;
+pub mod routing;
 pub mod threshold;
 
 pub use riscv_pac::{HartIdNumber, InterruptNumber, PriorityNumber}; // re-export useful riscv-pac traits
@@ -16,9 +17,35 @@ pub use riscv_pac::{HartIdNumber, InterruptNumber, PriorityNumber}; // re-export
 ///
 /// * This trait must only be implemented on a PAC of a target with a PLIC peripheral.
 /// * The PLIC peripheral base address `BASE` must be valid for the target device.
+/// * The register layout offsets and separations must be valid for the target device.
 pub unsafe trait Plic: Copy {
     /// Base address of the PLIC peripheral.
     const BASE: usize;
+
+    /// Offset of the interrupt priorities register with respect to [`Plic::BASE`].
+    const PRIORITIES_OFFSET: usize = 0;
+
+    /// Offset of the interrupt pendings register with respect to [`Plic::BASE`].
+    const PENDINGS_OFFSET: usize = 0x1000;
+
+    /// Offset of the interrupt enables register of context 0 with respect to [`Plic::BASE`].
+    const ENABLES_OFFSET: usize = 0x2000;
+    /// Separation between the interrupt enables registers of consecutive contexts.
+    const ENABLES_SEPARATION: usize = 0x80;
+
+    /// Offset of the priority threshold register of context 0 with respect to [`Plic::BASE`].
+    const THRESHOLDS_OFFSET: usize = 0x20_0000;
+    /// Separation between the priority threshold registers of consecutive contexts.
+    const THRESHOLDS_SEPARATION: usize = 0x1000;
+
+    /// Offset of the claim/complete register of context 0 with respect to [`Plic::BASE`].
+    const CLAIMS_OFFSET: usize = 0x20_0004;
+    /// Separation between the claim/complete registers of consecutive contexts.
+    const CLAIMS_SEPARATION: usize = 0x1000;
+
+    /// Highest context number of the PLIC peripheral.
+    /// By default, it is the maximum number of contexts allowed by the PLIC standard minus one.
+    const MAX_CONTEXT: usize = 15_871;
 }
 
 /// Platform-Level Interrupt Controler (PLIC) peripheral.
@@ -37,10 +64,61 @@ pub struct PLIC<P: Plic> {
     _marker: core::marker::PhantomData<P>,
 }
 
+#[cfg(feature = "test-mock")]
+std::thread_local! {
+    /// Base address override for the PLIC peripherals of the current thread.
+    static MOCK_BASE: core::cell::Cell<Option<usize>> = const { core::cell::Cell::new(None) };
+}
+
 impl<P: Plic> PLIC<P> {
-    const PRIORITIES_OFFSET: usize = 0;
+    /// Returns the base address of the PLIC.
+    ///
+    /// If the `test-mock` feature is enabled and a mock base address has been set
+    /// for the current thread, it returns the mock base address instead of [`Plic::BASE`].
+    #[inline]
+    fn base() -> usize {
+        #[cfg(feature = "test-mock")]
+        if let Some(base) = MOCK_BASE.with(|base| base.get()) {
+            return base;
+        }
+        P::BASE
+    }
 
-    const PENDINGS_OFFSET: usize = 0x1000;
+    /// Overrides the base address of the PLIC for the current thread.
+    /// This allows testing the PLIC logic on the host against a RAM buffer.
+    ///
+    /// # Note
+    ///
+    /// The override applies to all the PLIC peripherals of the current thread,
+    /// and only to context proxies created after calling this function.
+    /// The register layout is still defined by the [`Plic`] trait.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must be large enough to hold all the PLIC registers and must outlive its use.
+    #[cfg(feature = "test-mock")]
+    #[inline]
+    pub unsafe fn set_mock_base(base: *mut u32) {
+        MOCK_BASE.with(|b| b.set(Some(base as _)));
+    }
+
+    /// Removes the base address override of the PLIC for the current thread.
+    #[cfg(feature = "test-mock")]
+    #[inline]
+    pub fn clear_mock_base() {
+        MOCK_BASE.with(|b| b.set(None));
+    }
+
+    /// Maps a raw interrupt source number (e.g., from [`claim::CLAIM::claim_raw`]) to the typed interrupt source.
+    ///
+    /// It returns `Err(number)` if `number` is 0 (i.e., "no interrupt") or if it does not match any source.
+    #[inline]
+    pub fn source_from_number<I: InterruptNumber>(number: u16) -> Result<I, u16> {
+        match number {
+            0 => Err(0),
+            n => I::from_number(n),
+        }
+    }
 
     /// Returns the priorities register of the PLIC.
     /// This register allows to set the priority level of each interrupt source.
@@ -48,7 +126,7 @@ impl<P: Plic> PLIC<P> {
     #[inline]
     pub fn priorities() -> priorities::PRIORITIES {
         // SAFETY: valid address
-        unsafe { priorities::PRIORITIES::new(P::BASE + Self::PRIORITIES_OFFSET) }
+        unsafe { priorities::PRIORITIES::new(Self::base() + P::PRIORITIES_OFFSET) }
     }
 
     /// Returns the pendings register of the PLIC.
@@ -56,7 +134,7 @@ impl<P: Plic> PLIC<P> {
     #[inline]
     pub fn pendings() -> pendings::PENDINGS {
         // SAFETY: valid address
-        unsafe { pendings::PENDINGS::new(P::BASE + Self::PENDINGS_OFFSET) }
+        unsafe { pendings::PENDINGS::new(Self::base() + P::PENDINGS_OFFSET) }
     }
 
     /// Returns a proxy to access to all the PLIC registers of a given HART context.
@@ -66,6 +144,19 @@ impl<P: Plic> PLIC<P> {
         unsafe { CTX::new(hart_id.number()) }
     }
 
+    /// Returns a proxy to access to all the PLIC registers of the context with raw index `index`.
+    ///
+    /// This is an escape hatch for platforms whose context numbering is not tied to HART IDs.
+    /// It returns `Err(index)` if `index` is greater than [`Plic::MAX_CONTEXT`].
+    #[inline]
+    pub fn ctx_raw(index: usize) -> Result<CTX<P>, usize> {
+        match index {
+            // SAFETY: valid context number
+            i if i <= P::MAX_CONTEXT => Ok(unsafe { CTX::new(i as _) }),
+            i => Err(i),
+        }
+    }
+
     /// Returns the PLIC HART context for the current HART.
     ///
     /// # Note
@@ -78,26 +169,51 @@ impl<P: Plic> PLIC<P> {
         // SAFETY: `hart_id` is valid for the target and is the current hart
         unsafe { CTX::new(hart_id as _) }
     }
+
+    /// Returns the PLIC HART context for the current HART, checking that it is a valid context.
+    ///
+    /// Unlike [`PLIC::ctx_mhartid`], it returns `Err(hart_id)` if the current HART ID
+    /// does not correspond to any context of type `H`, instead of computing an invalid address.
+    ///
+    /// # Note
+    ///
+    /// This function determines the current HART ID by reading the [`riscv::register::mhartid`] CSR.
+    /// Thus, it can only be used in M-mode. For S-mode, use [`PLIC::ctx`] instead.
+    #[inline]
+    pub fn try_ctx_mhartid<H: HartIdNumber>() -> Result<CTX<P>, usize> {
+        Self::try_ctx_with::<H>(riscv::register::mhartid::read())
+    }
+
+    /// Same as [`PLIC::try_ctx_mhartid`], but using `mhartid` as the current HART ID.
+    #[inline]
+    fn try_ctx_with<H: HartIdNumber>(mhartid: usize) -> Result<CTX<P>, usize> {
+        crate::common::hart_id_from_mhartid::<H>(mhartid).map(Self::ctx)
+    }
 }
 
 /// PLIC context proxy. It provides access to the PLIC registers of a given context.
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(not(feature = "test-mock"), derive(Default))]
 pub struct CTX<P: Plic> {
+    /// Base address of the PLIC when the proxy was created (see [`PLIC::set_mock_base`]).
+    #[cfg(feature = "test-mock")]
+    base: usize,
     context: usize,
     _marker: core::marker::PhantomData<P>,
 }
 
-impl<P: Plic> CTX<P> {
-    const ENABLES_OFFSET: usize = 0x2000;
-    const ENABLES_SEPARATION: usize = 0x80;
-
-    const THRESHOLDS_OFFSET: usize = 0x20_0000;
-    const THRESHOLDS_SEPARATION: usize = 0x1000;
-
-    const CLAIMS_OFFSET: usize = 0x20_0004;
-    const CLAIMS_SEPARATION: usize = 0x1000;
+// Same bounds as the derived implementation, but capturing the mock base address
+#[cfg(feature = "test-mock")]
+impl<P: Plic + Default> Default for CTX<P> {
+    #[inline]
+    fn default() -> Self {
+        // SAFETY: context 0 is always valid
+        unsafe { Self::new(0) }
+    }
+}
 
+impl<P: Plic> CTX<P> {
     /// Creates a new PLIC context proxy
     ///
     /// # Safety
@@ -106,6 +222,8 @@ impl<P: Plic> CTX<P> {
     #[inline]
     pub(crate) unsafe fn new(context: u16) -> Self {
         Self {
+            #[cfg(feature = "test-mock")]
+            base: PLIC::<P>::base(),
             context: context as _,
             _marker: core::marker::PhantomData,
         }
@@ -117,10 +235,19 @@ impl<P: Plic> CTX<P> {
         self.context as _
     }
 
+    /// Returns the base address of the PLIC.
+    #[inline]
+    const fn base(self) -> usize {
+        #[cfg(feature = "test-mock")]
+        return self.base;
+        #[cfg(not(feature = "test-mock"))]
+        P::BASE
+    }
+
     /// Returns the interrupts enable register of the context.
     #[inline]
     pub const fn enables(self) -> enables::ENABLES {
-        let addr = P::BASE + Self::ENABLES_OFFSET + self.context * Self::ENABLES_SEPARATION;
+        let addr = self.base() + P::ENABLES_OFFSET + self.context * P::ENABLES_SEPARATION;
         // SAFETY: valid address
         unsafe { enables::ENABLES::new(addr) }
     }
@@ -128,7 +255,7 @@ impl<P: Plic> CTX<P> {
     /// Returns the interrupt threshold register of the context.
     #[inline]
     pub const fn threshold(self) -> threshold::THRESHOLD {
-        let addr = P::BASE + Self::THRESHOLDS_OFFSET + self.context * Self::THRESHOLDS_SEPARATION;
+        let addr = self.base() + P::THRESHOLDS_OFFSET + self.context * P::THRESHOLDS_SEPARATION;
         // SAFETY: valid address
         unsafe { threshold::THRESHOLD::new(addr) }
     }
@@ -136,7 +263,7 @@ impl<P: Plic> CTX<P> {
     /// Returns the interrupt claim/complete register of the context.
     #[inline]
     pub const fn claim(self) -> claim::CLAIM {
-        let addr = P::BASE + Self::CLAIMS_OFFSET + self.context * Self::CLAIMS_SEPARATION;
+        let addr = self.base() + P::CLAIMS_OFFSET + self.context * P::CLAIMS_SEPARATION;
         // SAFETY: valid address
         unsafe { claim::CLAIM::new(addr) }
     }
@@ -307,5 +434,137 @@ pub(crate) mod test {
         assert_eq!(PLIC::ctx0(), PLIC::ctx(Context::C0));
         assert_eq!(PLIC::ctx1(), PLIC::ctx(Context::C1));
         assert_eq!(PLIC::ctx2(), PLIC::ctx(Context::C2));
+
+        // without mocking, a context proxy only holds its context number
+        #[cfg(not(feature = "test-mock"))]
+        assert_eq!(
+            core::mem::size_of::<super::CTX<PLIC>>(),
+            core::mem::size_of::<usize>()
+        );
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn check_source_from_number() {
+        crate::plic_codegen!(base 0x0C00_0000,);
+
+        // raw claim values map back to the typed interrupt sources
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let claim = unsafe { super::claim::CLAIM::new(&mut raw_reg as *mut _ as _) };
+        assert_eq!(claim.claim_raw(), None);
+        for i in 1..=Interrupt::MAX_INTERRUPT_NUMBER {
+            claim.complete_raw(i);
+            let number = claim.claim_raw().unwrap();
+            assert_eq!(
+                PLIC::source_from_number(number),
+                Ok(Interrupt::from_number(i).unwrap())
+            );
+        }
+
+        assert_eq!(PLIC::source_from_number::<Interrupt>(2), Ok(Interrupt::I2));
+        assert_eq!(PLIC::source_from_number::<Interrupt>(0), Err(0));
+        assert_eq!(PLIC::source_from_number::<Interrupt>(5), Err(5));
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn check_try_ctx() {
+        crate::plic_codegen!(base 0x0C00_0000,);
+
+        let try_ctx = super::PLIC::<PLIC>::try_ctx_with::<Context>;
+        assert_eq!(try_ctx(1), Ok(PLIC::ctx(Context::C1)));
+        // a misconfigured system with more HARTs than PLIC contexts
+        assert_eq!(try_ctx(3), Err(3));
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn check_ctx_raw() {
+        crate::plic_codegen!(base 0x0C00_0000, layout [MAX_CONTEXT = 3],);
+
+        let ctx = PLIC::ctx_raw(2).unwrap();
+        assert_eq!(ctx.context(), 2);
+        assert_eq!(ctx, PLIC::ctx(Context::C2));
+        assert_eq!(PLIC::ctx_raw(3).map(|ctx| ctx.context()), Ok(3));
+        assert_eq!(PLIC::ctx_raw(4), Err(4));
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn check_ctx_raw_default_limit() {
+        crate::plic_codegen!(base 0x0C00_0000,);
+
+        // default layout: up to the maximum number of contexts of the PLIC standard
+        assert_eq!(PLIC::ctx_raw(15_871).map(|ctx| ctx.context()), Ok(15_871));
+        assert_eq!(PLIC::ctx_raw(15_872), Err(15_872));
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn check_plic_layout() {
+        crate::plic_codegen!(
+            base 0x0C00_0000,
+            layout [PENDINGS_OFFSET = 0x800, ENABLES_OFFSET = 0x4000, ENABLES_SEPARATION = 0x100, THRESHOLDS_SEPARATION = 0x2000, CLAIMS_SEPARATION = 0x2000],
+            ctxs [ctx0 = (Context::C0, "`C0`"), ctx1 = (Context::C1, "`C1`"), ctx2 = (Context::C2, "`C2`")],
+        );
+
+        assert_eq!(PLIC::priorities().address(), 0x0C00_0000);
+        assert_eq!(PLIC::pendings().address(), 0x0C00_0800);
+
+        let ctx = PLIC::ctx2();
+        assert_eq!(ctx.enables().address(), 0x0C00_0000 + 0x4000 + 2 * 0x100);
+        assert_eq!(
+            ctx.threshold().get_ptr() as usize,
+            0x0C00_0000 + 0x20_0000 + 2 * 0x2000
+        );
+        assert_eq!(
+            ctx.claim().get_ptr() as usize,
+            0x0C00_0000 + 0x20_0004 + 2 * 0x2000
+        );
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[allow(dead_code)]
+    #[test]
+    fn check_plic_mock() {
+        crate::plic_codegen!(
+            base 0x0C00_0000,
+            layout [PENDINGS_OFFSET = 0x20, ENABLES_OFFSET = 0x40, ENABLES_SEPARATION = 0x10, THRESHOLDS_OFFSET = 0x80, THRESHOLDS_SEPARATION = 0x10, CLAIMS_OFFSET = 0x84, CLAIMS_SEPARATION = 0x10],
+        );
+
+        // buffer to emulate the PLIC registers
+        let mut raw_regs = [0u32; 0x40];
+        // SAFETY: the buffer holds all the PLIC registers and outlives the test
+        unsafe { super::PLIC::<PLIC>::set_mock_base(raw_regs.as_mut_ptr()) };
+        let ctx = PLIC::ctx(Context::C1);
+        let priorities = PLIC::priorities();
+        super::PLIC::<PLIC>::clear_mock_base();
+
+        assert_eq!(priorities.address(), raw_regs.as_ptr() as usize);
+        assert_eq!(ctx.enables().address(), raw_regs.as_ptr() as usize + 0x50);
+        assert_eq!(PLIC::ctx(Context::C1).enables().address(), 0x0C00_0050);
+
+        unsafe {
+            priorities.set_priority(Interrupt::I2, Priority::P3);
+            ctx.enables().enable(Interrupt::I2);
+            ctx.threshold().set_threshold(Priority::P1);
+        }
+        assert_eq!(raw_regs[Interrupt::I2 as usize], Priority::P3 as u32);
+        assert_eq!(raw_regs[0x50 / 4], 1 << Interrupt::I2 as u32);
+        assert_eq!(raw_regs[0x90 / 4], Priority::P1 as u32);
+
+        // no interrupt pending
+        assert_eq!(ctx.claim().claim::<Interrupt>(), None);
+
+        // the PLIC gateway forwards a pending interrupt to the claim register
+        raw_regs[0x94 / 4] = Interrupt::I2 as u32;
+        let source = ctx.claim().claim::<Interrupt>().unwrap();
+        assert_eq!(source, Interrupt::I2);
+
+        // the PLIC gateway clears the claim register after claiming
+        raw_regs[0x94 / 4] = 0;
+        ctx.claim().complete(source);
+        assert_eq!(raw_regs[0x94 / 4], Interrupt::I2 as u32);
     }
 }
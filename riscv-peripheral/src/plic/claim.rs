@@ -15,6 +15,33 @@ impl CLAIM {
         }
     }
 
+    /// Claims the raw number of a pending interrupt for the PLIC context.
+    /// If no interrupt is pending for this context, it returns [`None`].
+    ///
+    /// Unlike [`CLAIM::claim`], it does not convert the number to an [`InterruptNumber`].
+    /// Use [`crate::plic::PLIC::source_from_number`] to map it to the typed interrupt source.
+    #[inline]
+    pub fn claim_raw(self) -> Option<u16> {
+        match self.register.read() {
+            0 => None,
+            i => Some(i as _),
+        }
+    }
+
+    /// Claims a pending interrupt for the PLIC context and returns a [`ClaimGuard`]
+    /// that remembers the claimed source.
+    /// If no interrupt is pending for this context, it returns [`None`].
+    ///
+    /// Completing the guard with a different source is a handler bug.
+    /// In debug builds, [`ClaimGuard::complete`] panics on such a mismatch.
+    #[inline]
+    pub fn claim_guarded<I: InterruptNumber>(self) -> Option<ClaimGuard<I>> {
+        self.claim().map(|source| ClaimGuard {
+            claim: self,
+            source,
+        })
+    }
+
     /// Marks a pending interrupt as complete for the PLIC context.
     ///
     /// # Note
@@ -25,6 +52,52 @@ impl CLAIM {
     pub fn complete<I: InterruptNumber>(self, source: I) {
         self.register.write(source.number() as _)
     }
+
+    /// Marks a pending interrupt as complete for the PLIC context using its raw number.
+    ///
+    /// # Note
+    ///
+    /// If the source ID does not match an interrupt source that is
+    /// currently enabled for the target, the completion is silently ignored.
+    #[inline]
+    pub fn complete_raw(self, source: u16) {
+        self.register.write(source as _)
+    }
+}
+
+/// Interrupt source claimed with [`CLAIM::claim_guarded`].
+///
+/// Some PLICs silently ignore completions of sources that were not claimed.
+/// The guard keeps track of the last claimed source to catch these bugs during development.
+/// It is neither `Clone` nor `Copy`, so [`ClaimGuard::complete`] consumes the only handle to the claim.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ClaimGuard<I> {
+    claim: CLAIM,
+    source: I,
+}
+
+impl<I: InterruptNumber> ClaimGuard<I> {
+    /// Returns the claimed interrupt source.
+    #[inline]
+    pub const fn source(&self) -> I {
+        self.source
+    }
+
+    /// Marks the claimed interrupt as complete for the PLIC context.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, it panics if `source` does not match the claimed source.
+    /// In release builds, `source` is written to the claim/complete register without checks.
+    #[inline]
+    pub fn complete(self, source: I) {
+        debug_assert_eq!(
+            source.number(),
+            self.source.number(),
+            "completing a PLIC source that was not claimed"
+        );
+        self.claim.complete(source)
+    }
 }
 
 #[cfg(test)]
@@ -46,4 +119,32 @@ mod test {
             assert_eq!(claim.claim(), Some(interrupt));
         }
     }
+
+    #[test]
+    fn test_claim_guarded() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let claim = unsafe { CLAIM::new(&mut raw_reg as *mut _ as _) };
+
+        assert_eq!(claim.claim_guarded::<Interrupt>(), None);
+
+        claim.complete(Interrupt::I2);
+        let guard = claim.claim_guarded::<Interrupt>().unwrap();
+        assert_eq!(guard.source(), Interrupt::I2);
+        guard.complete(Interrupt::I2);
+        assert_eq!(raw_reg, Interrupt::I2 as u32);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "completing a PLIC source that was not claimed")]
+    fn test_claim_guarded_mismatch() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let claim = unsafe { CLAIM::new(&mut raw_reg as *mut _ as _) };
+
+        claim.complete(Interrupt::I1);
+        let guard = claim.claim_guarded::<Interrupt>().unwrap();
+        guard.complete(Interrupt::I3);
+    }
 }
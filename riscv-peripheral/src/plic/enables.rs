@@ -63,6 +63,8 @@ impl ENABLES {
     /// # Note
     ///
     /// This method is only available on targets that support atomic operations on 32-bit registers.
+    /// On RISC-V targets with the A extension, it compiles down to a single `amoor.w` instruction.
+    /// Thus, it does not race with other HARTs enabling or disabling sources in the same register.
     ///
     /// # Safety
     ///
@@ -102,6 +104,7 @@ impl ENABLES {
     /// # Note
     ///
     /// This method is only available on targets that support atomic operations on 32-bit registers.
+    /// On RISC-V targets with the A extension, it compiles down to a single `amoand.w` instruction.
     ///
     /// # Safety
     ///
@@ -143,6 +146,52 @@ impl ENABLES {
             reg.write(0);
         }
     }
+
+    /// Returns the number of enable words needed to cover all the interrupt sources of type `I`.
+    #[inline]
+    const fn n_words<I: InterruptNumber>() -> usize {
+        I::MAX_INTERRUPT_NUMBER as usize / u32::BITS as usize + 1
+    }
+
+    /// Writes a precomputed enable bitmap to the first `words.len()` enable words of the PLIC context.
+    ///
+    /// Bit `i` of `words[j]` enables the interrupt source `32 * j + i`.
+    /// This is faster than enabling sources one by one when the whole mask is known
+    /// (e.g., when restoring a configuration saved with [`ENABLES::read_words`]).
+    ///
+    /// # Panics
+    ///
+    /// It panics if `words` is longer than the number of enable words of the interrupt sources of type `I`.
+    ///
+    /// # Safety
+    ///
+    /// * Enabling interrupt sources can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn write_words<I: InterruptNumber>(self, words: &[u32]) {
+        assert!(words.len() <= Self::n_words::<I>());
+        for (offset, &word) in words.iter().enumerate() {
+            // SAFETY: valid offset
+            let reg: Reg<u32, RW> = unsafe { Reg::new(self.ptr.add(offset)) };
+            reg.write(word);
+        }
+    }
+
+    /// Reads the first `words.len()` enable words of the PLIC context into `words`.
+    ///
+    /// Bit `i` of `words[j]` is set if the interrupt source `32 * j + i` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `words` is longer than the number of enable words of the interrupt sources of type `I`.
+    #[inline]
+    pub fn read_words<I: InterruptNumber>(self, words: &mut [u32]) {
+        assert!(words.len() <= Self::n_words::<I>());
+        for (offset, word) in words.iter_mut().enumerate() {
+            // SAFETY: valid offset
+            let reg: Reg<u32, RW> = unsafe { Reg::new(self.ptr.add(offset)) };
+            *word = reg.read();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -198,6 +247,71 @@ mod test {
         }
     }
 
+    /// Interrupt sources spanning several enable words.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct WideInterrupt(u16);
+
+    unsafe impl InterruptNumber for WideInterrupt {
+        const MAX_INTERRUPT_NUMBER: u16 = 70;
+
+        #[inline]
+        fn number(self) -> u16 {
+            self.0
+        }
+
+        #[inline]
+        fn from_number(number: u16) -> Result<Self, u16> {
+            match number {
+                1..=Self::MAX_INTERRUPT_NUMBER => Ok(Self(number)),
+                _ => Err(number),
+            }
+        }
+    }
+
+    #[test]
+    fn test_enables_words() {
+        // slice to emulate the interrupt enables register
+        let mut raw_reg = [0u32; 32];
+        // SAFETY: valid memory address
+        let enables = unsafe { ENABLES::new(raw_reg.as_mut_ptr() as _) };
+
+        let bitmap = [0x8000_0002, 0x0000_0001, 0x0000_0040];
+        unsafe { enables.write_words::<WideInterrupt>(&bitmap) };
+        for source in 1..=WideInterrupt::MAX_INTERRUPT_NUMBER {
+            let enabled = matches!(source, 1 | 31 | 32 | 70);
+            assert_eq!(enables.is_enabled(WideInterrupt(source)), enabled);
+        }
+
+        let mut saved = [0; 3];
+        enables.read_words::<WideInterrupt>(&mut saved);
+        assert_eq!(saved, bitmap);
+
+        // restore a saved configuration
+        enables.disable_all::<WideInterrupt>();
+        unsafe { enables.enable(WideInterrupt(5)) };
+        unsafe { enables.write_words::<WideInterrupt>(&saved) };
+        assert!(!enables.is_enabled(WideInterrupt(5)));
+        assert!(enables.is_enabled(WideInterrupt(70)));
+
+        // partial accesses only touch the first words
+        unsafe { enables.write_words::<WideInterrupt>(&[0]) };
+        let mut partial = [0xFFFF_FFFF; 2];
+        enables.read_words::<WideInterrupt>(&mut partial);
+        assert_eq!(partial, [0, 1]);
+        assert_eq!(raw_reg[2], 0x40);
+        assert_eq!(raw_reg[3], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_enables_words_out_of_range() {
+        let mut raw_reg = [0u32; 32];
+        // SAFETY: valid memory address
+        let enables = unsafe { ENABLES::new(raw_reg.as_mut_ptr() as _) };
+        // 4 sources fit in a single enable word
+        unsafe { enables.write_words::<Interrupt>(&[0, 0]) };
+    }
+
     #[cfg(target_has_atomic = "32")]
     #[test]
     fn test_atomic_enables() {
@@ -235,4 +349,45 @@ mod test {
             assert_eq!(enables.is_enabled(Interrupt::I4), i & 0x10 != 0);
         }
     }
+
+    #[cfg(target_has_atomic = "32")]
+    #[test]
+    fn test_atomic_enables_match() {
+        // true atomicity can only be checked on hardware; here we check that
+        // the atomic and non-atomic paths leave the register in the same state
+        use core::sync::atomic::Ordering;
+        let mut raw_reg = [0u32; 32];
+        let mut raw_atomic_reg = [0u32; 32];
+        // SAFETY: valid memory addresses
+        let (enables, atomic_enables) = unsafe {
+            (
+                ENABLES::new(raw_reg.as_mut_ptr() as _),
+                ENABLES::new(raw_atomic_reg.as_mut_ptr() as _),
+            )
+        };
+
+        let sources = [Interrupt::I1, Interrupt::I2, Interrupt::I3, Interrupt::I4];
+        for i in 0..255 {
+            for (bit, &source) in sources.iter().enumerate() {
+                if i & (1 << bit) != 0 {
+                    unsafe {
+                        enables.enable(source);
+                        atomic_enables.atomic_enable(source, Ordering::SeqCst);
+                    }
+                } else {
+                    enables.disable(source);
+                    unsafe { atomic_enables.atomic_disable(source, Ordering::SeqCst) };
+                }
+            }
+            // SAFETY: valid memory addresses
+            let (word, atomic_word) = unsafe {
+                (
+                    raw_reg.as_ptr().read_volatile(),
+                    raw_atomic_reg.as_ptr().read_volatile(),
+                )
+            };
+            assert_eq!(word, atomic_word);
+            assert_eq!(word, (i & 0xF) << 1);
+        }
+    }
 }
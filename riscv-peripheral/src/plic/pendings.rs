@@ -38,6 +38,28 @@ impl PENDINGS {
         let reg: Reg<u32, RO> = unsafe { Reg::new(self.ptr.offset(offset)) };
         reg.read_bit(source % u32::BITS as usize)
     }
+
+    /// Returns an iterator over the pending interrupt sources, in ascending source number order.
+    ///
+    /// # Note
+    ///
+    /// Each 32-bit word of the register is read only once, when the iterator reaches it.
+    /// Source 0 and bits that do not correspond to a valid interrupt source are skipped.
+    #[inline]
+    pub fn iter_pending<I: InterruptNumber>(self) -> impl Iterator<Item = I> {
+        let n_words = I::MAX_INTERRUPT_NUMBER as usize / u32::BITS as usize + 1;
+        (0..n_words)
+            .flat_map(move |offset| {
+                // SAFETY: valid interrupt number
+                let reg: Reg<u32, RO> = unsafe { Reg::new(self.ptr.add(offset)) };
+                let bits = reg.read();
+                (0..u32::BITS as usize)
+                    .filter(move |bit| bits & (1 << bit) != 0)
+                    .map(move |bit| offset * u32::BITS as usize + bit)
+            })
+            .filter(|&source| source != 0)
+            .filter_map(|source| I::from_number(source as _).ok())
+    }
 }
 
 #[cfg(test)]
@@ -61,4 +83,60 @@ mod test {
             assert_eq!(pendings.is_pending(Interrupt::I4), i & 0x10 != 0);
         }
     }
+
+    #[test]
+    fn test_iter_pending() {
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        struct Source(u16);
+
+        unsafe impl InterruptNumber for Source {
+            const MAX_INTERRUPT_NUMBER: u16 = 64;
+
+            #[inline]
+            fn number(self) -> u16 {
+                self.0
+            }
+
+            #[inline]
+            fn from_number(number: u16) -> Result<Self, u16> {
+                if number > Self::MAX_INTERRUPT_NUMBER || number == 0 {
+                    Err(number)
+                } else {
+                    Ok(Self(number))
+                }
+            }
+        }
+
+        // slice to emulate the interrupt pendings register
+        let mut raw_reg = [0u32; 4];
+        // SAFETY: valid memory address
+        let pendings = unsafe { PENDINGS::new(raw_reg.as_mut_ptr() as _) };
+        assert_eq!(pendings.iter_pending::<Source>().count(), 0);
+
+        // sources at word boundaries, plus source 0 and out-of-range sources that must be skipped
+        let words = [
+            (1 << 0) | (1 << 1) | (1 << 31),
+            (1 << 0) | (1 << 31),
+            0b11,
+            1,
+        ];
+        for (i, word) in words.into_iter().enumerate() {
+            // SAFETY: valid memory address
+            unsafe { raw_reg.as_mut_ptr().add(i).write_volatile(word) };
+        }
+        assert!(pendings
+            .iter_pending::<Source>()
+            .map(|source| source.0)
+            .eq([1, 31, 32, 63, 64]));
+
+        for (i, word) in [0b1_1010, 0, 0, 0].into_iter().enumerate() {
+            // SAFETY: valid memory address
+            unsafe { raw_reg.as_mut_ptr().add(i).write_volatile(word) };
+        }
+        assert!(pendings.iter_pending::<Interrupt>().eq([
+            Interrupt::I1,
+            Interrupt::I3,
+            Interrupt::I4
+        ]));
+    }
 }
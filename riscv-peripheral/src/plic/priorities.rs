@@ -29,6 +29,34 @@ impl PRIORITIES {
         self.ptr as _
     }
 
+    /// Returns the raw priority level assigned to a given interrupt source.
+    ///
+    /// Unlike [`PRIORITIES::get_priority`], it does not convert the value to a [`PriorityNumber`].
+    /// Thus, it is useful for diagnostics, as it never panics on unexpected values.
+    #[inline]
+    pub fn get<I: InterruptNumber>(self, source: I) -> u32 {
+        // SAFETY: valid interrupt number
+        let reg: Reg<u32, RW> = unsafe { Reg::new(self.ptr.offset(source.number() as _)) };
+        reg.read()
+    }
+
+    /// Reads the raw priority levels of the interrupt sources `1..=N` into a [`PrioritySnapshot`].
+    ///
+    /// # Note
+    ///
+    /// `N` is usually the maximum interrupt number of the target (i.e., [`InterruptNumber::MAX_INTERRUPT_NUMBER`]).
+    /// Reading beyond the interrupt sources supported by the PLIC returns implementation-defined values.
+    #[inline]
+    pub fn snapshot<const N: usize>(self) -> PrioritySnapshot<N> {
+        let mut priorities = [0; N];
+        for (i, priority) in priorities.iter_mut().enumerate() {
+            // SAFETY: interrupt number within range
+            let reg: Reg<u32, RW> = unsafe { Reg::new(self.ptr.add(i + 1)) };
+            *priority = reg.read();
+        }
+        PrioritySnapshot { priorities }
+    }
+
     /// Returns the priority assigned to a given interrupt source.
     #[inline]
     pub fn get_priority<I: InterruptNumber, P: PriorityNumber>(self, source: I) -> P {
@@ -69,6 +97,45 @@ impl PRIORITIES {
     }
 }
 
+/// Snapshot of the raw priority levels of the interrupt sources `1..=N`.
+///
+/// It is obtained with [`PRIORITIES::snapshot`], and iterates over `(source, priority)` pairs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PrioritySnapshot<const N: usize> {
+    priorities: [u32; N],
+}
+
+impl<const N: usize> PrioritySnapshot<N> {
+    /// Returns the raw priority level of the interrupt source `source`.
+    ///
+    /// It returns `None` if `source` is 0 or greater than `N`.
+    #[inline]
+    pub fn get(&self, source: u16) -> Option<u32> {
+        let index = (source as usize).checked_sub(1)?;
+        self.priorities.get(index).copied()
+    }
+
+    /// Returns an iterator over the `(source, priority)` pairs of the snapshot.
+    #[inline]
+    pub fn iter(&self) -> PrioritySnapshotIter<'_> {
+        (1..).zip(self.priorities.iter().copied())
+    }
+}
+
+/// Iterator over the `(source, priority)` pairs of a [`PrioritySnapshot`].
+pub type PrioritySnapshotIter<'a> =
+    core::iter::Zip<core::ops::RangeFrom<u16>, core::iter::Copied<core::slice::Iter<'a, u32>>>;
+
+impl<'a, const N: usize> IntoIterator for &'a PrioritySnapshot<N> {
+    type Item = (u16, u32);
+    type IntoIter = PrioritySnapshotIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::test::{Interrupt, Priority};
@@ -95,4 +162,41 @@ mod test {
             assert_eq!(priorities.get_priority::<_, Priority>(source), Priority::P0);
         }
     }
+
+    #[test]
+    fn test_priorities_snapshot() {
+        // slice to emulate the interrupt priorities register
+        let mut raw_reg = [0u32; 1024];
+        // SAFETY: valid memory address
+        let priorities = unsafe { PRIORITIES::new(raw_reg.as_mut_ptr() as _) };
+
+        unsafe {
+            priorities.set_priority(Interrupt::I1, Priority::P3);
+            priorities.set_priority(Interrupt::I2, Priority::P1);
+            priorities.set_priority(Interrupt::I4, Priority::P2);
+        }
+        assert_eq!(priorities.get(Interrupt::I1), 3);
+        assert_eq!(priorities.get(Interrupt::I2), 1);
+        assert_eq!(priorities.get(Interrupt::I3), 0);
+        assert_eq!(priorities.get(Interrupt::I4), 2);
+
+        const N: usize = Interrupt::MAX_INTERRUPT_NUMBER as usize;
+        let snapshot = priorities.snapshot::<N>();
+        assert_eq!(snapshot.get(0), None);
+        assert_eq!(snapshot.get(1), Some(3));
+        assert_eq!(snapshot.get(3), Some(0));
+        assert_eq!(snapshot.get(4), Some(2));
+        assert_eq!(snapshot.get(5), None);
+        assert!(snapshot.iter().eq([(1, 3), (2, 1), (3, 0), (4, 2)]));
+
+        // the snapshot is not affected by later changes
+        priorities.reset::<Interrupt>();
+        assert_eq!(priorities.get(Interrupt::I1), 0);
+        let mut count = 0;
+        for (source, priority) in &snapshot {
+            assert_eq!(snapshot.get(source), Some(priority));
+            count += 1;
+        }
+        assert_eq!(count, N);
+    }
 }
@@ -0,0 +1,241 @@
+//! Interrupt routing table builder.
+
+use crate::plic::{HartIdNumber, InterruptNumber, Plic, PriorityNumber, PLIC};
+
+/// Errors that may occur when adding routes to a [`RoutingBuilder`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoutingError {
+    /// The routing table is full.
+    Full,
+    /// The interrupt source was already routed with a different priority.
+    /// Interrupt priorities are shared among all the PLIC contexts.
+    PriorityConflict(u16),
+}
+
+/// A single entry of a PLIC routing table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Route<I, H, Q> {
+    /// Interrupt source.
+    pub source: I,
+    /// Context that receives the interrupt source.
+    pub context: H,
+    /// Priority level of the interrupt source.
+    pub priority: Q,
+    /// Whether the interrupt source must be enabled for the context.
+    pub enable: bool,
+}
+
+/// Builder of PLIC routing tables.
+///
+/// It collects up to `N` routes (i.e., which interrupt source goes to which context
+/// with which priority) and applies all of them in a single [`RoutingBuilder::commit`] call.
+#[derive(Clone, Copy, Debug)]
+pub struct RoutingBuilder<P: Plic, I, H, Q, const N: usize> {
+    routes: [Option<Route<I, H, Q>>; N],
+    len: usize,
+    _marker: core::marker::PhantomData<P>,
+}
+
+impl<P: Plic, I, H, Q, const N: usize> Default for RoutingBuilder<P, I, H, Q, N>
+where
+    I: InterruptNumber,
+    H: HartIdNumber,
+    Q: PriorityNumber,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Plic, I, H, Q, const N: usize> RoutingBuilder<P, I, H, Q, N>
+where
+    I: InterruptNumber,
+    H: HartIdNumber,
+    Q: PriorityNumber,
+{
+    /// Creates a new empty routing table.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            routes: [None; N],
+            len: 0,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the routes added so far.
+    #[inline]
+    pub fn routes(&self) -> impl Iterator<Item = &Route<I, H, Q>> {
+        self.routes[..self.len].iter().flatten()
+    }
+
+    /// Adds a route to the routing table.
+    ///
+    /// # Errors
+    ///
+    /// * [`RoutingError::Full`] if the table already holds `N` routes.
+    /// * [`RoutingError::PriorityConflict`] if `source` was already routed with a different priority.
+    #[inline]
+    pub fn route(
+        &mut self,
+        source: I,
+        context: H,
+        priority: Q,
+        enable: bool,
+    ) -> Result<&mut Self, RoutingError> {
+        let conflict = self.routes().any(|route| {
+            route.source.number() == source.number() && route.priority.number() != priority.number()
+        });
+        if conflict {
+            return Err(RoutingError::PriorityConflict(source.number()));
+        }
+        if self.len == N {
+            return Err(RoutingError::Full);
+        }
+        self.routes[self.len] = Some(Route {
+            source,
+            context,
+            priority,
+            enable,
+        });
+        self.len += 1;
+        Ok(self)
+    }
+
+    /// Applies the routing table to the PLIC.
+    ///
+    /// For each route, it sets the priority of the interrupt source,
+    /// and enables or disables the interrupt source for the context.
+    ///
+    /// # Note
+    ///
+    /// It performs non-atomic read-modify-write operations on the enable registers.
+    ///
+    /// # Safety
+    ///
+    /// Changing priorities and enabling interrupt sources can break priority-based
+    /// and mask-based critical sections.
+    #[inline]
+    pub unsafe fn commit(&self) {
+        let priorities = PLIC::<P>::priorities();
+        for route in self.routes() {
+            priorities.set_priority(route.source, route.priority);
+            let enables = PLIC::<P>::ctx(route.context).enables();
+            match route.enable {
+                true => enables.enable(route.source),
+                false => enables.disable(route.source),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::{Context, Interrupt, Priority};
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct TestPlic;
+
+    // SAFETY: only used for host tests
+    unsafe impl Plic for TestPlic {
+        const BASE: usize = 0x0C00_0000;
+    }
+
+    #[test]
+    fn test_routing_builder() {
+        let mut builder = RoutingBuilder::<TestPlic, Interrupt, Context, Priority, 3>::new();
+        assert_eq!(builder.routes().count(), 0);
+
+        builder
+            .route(Interrupt::I1, Context::C0, Priority::P1, true)
+            .unwrap()
+            .route(Interrupt::I1, Context::C1, Priority::P1, false)
+            .unwrap();
+        assert_eq!(builder.routes().count(), 2);
+
+        assert_eq!(
+            builder
+                .route(Interrupt::I1, Context::C2, Priority::P2, true)
+                .err(),
+            Some(RoutingError::PriorityConflict(Interrupt::I1 as u16))
+        );
+        builder
+            .route(Interrupt::I2, Context::C2, Priority::P2, true)
+            .unwrap();
+        assert_eq!(
+            builder
+                .route(Interrupt::I3, Context::C2, Priority::P3, true)
+                .err(),
+            Some(RoutingError::Full)
+        );
+        assert_eq!(
+            builder.routes().last(),
+            Some(&Route {
+                source: Interrupt::I2,
+                context: Context::C2,
+                priority: Priority::P2,
+                enable: true,
+            })
+        );
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_routing_commit() {
+        // PLIC with 3 contexts, all registers fitting in a small buffer
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        struct MockPlic;
+
+        // SAFETY: only used with a mock base address
+        unsafe impl Plic for MockPlic {
+            const BASE: usize = 0x0C00_0000;
+            const PENDINGS_OFFSET: usize = 0x20;
+            const ENABLES_OFFSET: usize = 0x40;
+            const ENABLES_SEPARATION: usize = 0x10;
+            const THRESHOLDS_OFFSET: usize = 0x80;
+            const THRESHOLDS_SEPARATION: usize = 0x10;
+            const CLAIMS_OFFSET: usize = 0x84;
+            const CLAIMS_SEPARATION: usize = 0x10;
+        }
+
+        // buffer to emulate the PLIC registers
+        let mut raw_regs = [0u32; 0x40];
+        // context 1 has interrupt source 3 enabled beforehand
+        raw_regs[0x50 / 4] = 1 << Interrupt::I3 as u32;
+
+        let mut builder = RoutingBuilder::<MockPlic, Interrupt, Context, Priority, 4>::new();
+        builder
+            .route(Interrupt::I1, Context::C0, Priority::P1, true)
+            .unwrap()
+            .route(Interrupt::I2, Context::C1, Priority::P3, true)
+            .unwrap()
+            .route(Interrupt::I2, Context::C2, Priority::P3, true)
+            .unwrap()
+            .route(Interrupt::I3, Context::C1, Priority::P2, false)
+            .unwrap();
+
+        // SAFETY: the buffer holds all the PLIC registers and outlives the test
+        unsafe {
+            PLIC::<MockPlic>::set_mock_base(raw_regs.as_mut_ptr());
+            builder.commit();
+        }
+        PLIC::<MockPlic>::clear_mock_base();
+
+        // priorities
+        assert_eq!(raw_regs[0], 0);
+        assert_eq!(raw_regs[1], Priority::P1 as u32);
+        assert_eq!(raw_regs[2], Priority::P3 as u32);
+        assert_eq!(raw_regs[3], Priority::P2 as u32);
+        assert_eq!(raw_regs[4], 0);
+        // enables
+        assert_eq!(raw_regs[0x40 / 4], 1 << Interrupt::I1 as u32);
+        assert_eq!(raw_regs[0x50 / 4], 1 << Interrupt::I2 as u32);
+        assert_eq!(raw_regs[0x60 / 4], 1 << Interrupt::I2 as u32);
+        // thresholds are untouched
+        assert_eq!(raw_regs[0x80 / 4], 0);
+        assert_eq!(raw_regs[0x90 / 4], 0);
+        assert_eq!(raw_regs[0xA0 / 4], 0);
+    }
+}
@@ -49,6 +49,38 @@ instruction!(
     /// Provides a hint to the implementation that the current hart can be stalled until an interrupt might need servicing.
     /// The WFI instruction is just a hint, and a legal implementation is to implement WFI as a NOP.
     , wfi, "wfi");
+instruction!(
+    /// `WRS.NTO` instruction wrapper (Zawrs extension)
+    ///
+    /// Stalls the current hart until a reservation set registered with a previous `LR`
+    /// instruction is invalidated (e.g., another hart writes to the monitored memory location),
+    /// an interrupt becomes pending, or an implementation-defined condition occurs.
+    /// There is no timeout, but the hart may resume spuriously. Thus, the awaited condition
+    /// must always be checked again after this instruction.
+    #[cfg(feature = "zawrs")]
+    , wrs_nto, ".insn i 0x73, 0, x0, x0, 0x00d");
+instruction!(
+    /// `WRS.STO` instruction wrapper (Zawrs extension)
+    ///
+    /// Same as [`wrs_nto`], but the stall is also terminated after a short, implementation-defined timeout.
+    /// The hart may resume spuriously. Thus, the awaited condition must always be checked again
+    /// after this instruction.
+    #[cfg(feature = "zawrs")]
+    , wrs_sto, ".insn i 0x73, 0, x0, x0, 0x01d");
+instruction!(
+    /// `LPAD` instruction wrapper (Zicfilp extension)
+    ///
+    /// Marks a 4-byte aligned landing pad with label 0, which matches any expected label.
+    /// When landing pads are enabled for the current privilege mode (see `xenvcfg.LPE` and `mseccfg.MLPE`),
+    /// indirect jumps must target a landing pad. Otherwise, it is an `AUIPC x0, 0` hint and does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// riscv::asm::lpad();
+    /// ```
+    #[cfg(feature = "zicfilp")]
+    , lpad, ".p2align 2\n.insn u 0x17, x0, 0");
 instruction!(
     /// `SFENCE.VMA` instruction wrapper (all address spaces and page table levels)
     ///
@@ -161,3 +193,274 @@ pub fn delay(cycles: u32) {
         () => unimplemented!(),
     }
 }
+
+/// `PREFETCH.I` instruction wrapper (Zicbop extension)
+///
+/// Hints that the cache block containing `addr` is likely to be accessed by an instruction fetch
+/// in the near future. It is only a hint: implementations may ignore it, and it never raises exceptions.
+#[cfg(feature = "zicbop")]
+#[inline]
+#[allow(unused_variables)]
+pub fn prefetch_i(addr: usize) {
+    match () {
+        // prefetch.i 0({0})
+        #[cfg(riscv)]
+        () => unsafe {
+            core::arch::asm!("ori x0, {0}, 0", in(reg) addr, options(nostack, readonly))
+        },
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `PREFETCH.R` instruction wrapper (Zicbop extension)
+///
+/// Hints that the cache block containing `addr` is likely to be read in the near future.
+/// It is only a hint: implementations may ignore it, and it never raises exceptions.
+#[cfg(feature = "zicbop")]
+#[inline]
+#[allow(unused_variables)]
+pub fn prefetch_r(addr: usize) {
+    match () {
+        // prefetch.r 0({0})
+        #[cfg(riscv)]
+        () => unsafe {
+            core::arch::asm!("ori x0, {0}, 1", in(reg) addr, options(nostack, readonly))
+        },
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `PREFETCH.W` instruction wrapper (Zicbop extension)
+///
+/// Hints that the cache block containing `addr` is likely to be written in the near future.
+/// It is only a hint: implementations may ignore it, and it never raises exceptions.
+#[cfg(feature = "zicbop")]
+#[inline]
+#[allow(unused_variables)]
+pub fn prefetch_w(addr: usize) {
+    match () {
+        // prefetch.w 0({0})
+        #[cfg(riscv)]
+        () => unsafe {
+            core::arch::asm!("ori x0, {0}, 3", in(reg) addr, options(nostack, readonly))
+        },
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+#[cfg(feature = "zimop")]
+macro_rules! mop {
+    (r, $rs1:expr, $funct7:literal, $rs2:literal) => {
+        match () {
+            #[cfg(riscv)]
+            () => {
+                let rd: usize;
+                unsafe {
+                    core::arch::asm!(
+                        concat!(".insn r 0x73, 0x4, ", stringify!($funct7), ", {0}, {1}, ", $rs2),
+                        out(reg) rd,
+                        in(reg) $rs1,
+                        options(nomem, nostack),
+                    )
+                };
+                rd
+            }
+
+            #[cfg(not(riscv))]
+            () => unimplemented!(),
+        }
+    };
+    (rr, $rs1:expr, $rs2:expr, $funct7:literal) => {
+        match () {
+            #[cfg(riscv)]
+            () => {
+                let rd: usize;
+                unsafe {
+                    core::arch::asm!(
+                        concat!(".insn r 0x73, 0x4, ", stringify!($funct7), ", {0}, {1}, {2}"),
+                        out(reg) rd,
+                        in(reg) $rs1,
+                        in(reg) $rs2,
+                        options(nomem, nostack),
+                    )
+                };
+                rd
+            }
+
+            #[cfg(not(riscv))]
+            () => unimplemented!(),
+        }
+    };
+}
+
+/// `MOP.R.n` instruction wrapper (Zimop extension)
+///
+/// May-be-operation with one source register. Unless redefined by another extension,
+/// `MOP.R.n` only writes 0 to its destination register. Thus, this function returns 0.
+/// Future extensions (e.g., control-flow integrity) may redefine some of these instructions.
+///
+/// # Panics
+///
+/// This function panics if `n` is greater than 31.
+#[cfg(feature = "zimop")]
+#[inline]
+#[allow(unused_variables)]
+pub fn mop_r(n: usize, rs1: usize) -> usize {
+    match n {
+        0 => mop!(r, rs1, 64, "x28"),
+        1 => mop!(r, rs1, 64, "x29"),
+        2 => mop!(r, rs1, 64, "x30"),
+        3 => mop!(r, rs1, 64, "x31"),
+        4 => mop!(r, rs1, 66, "x28"),
+        5 => mop!(r, rs1, 66, "x29"),
+        6 => mop!(r, rs1, 66, "x30"),
+        7 => mop!(r, rs1, 66, "x31"),
+        8 => mop!(r, rs1, 68, "x28"),
+        9 => mop!(r, rs1, 68, "x29"),
+        10 => mop!(r, rs1, 68, "x30"),
+        11 => mop!(r, rs1, 68, "x31"),
+        12 => mop!(r, rs1, 70, "x28"),
+        13 => mop!(r, rs1, 70, "x29"),
+        14 => mop!(r, rs1, 70, "x30"),
+        15 => mop!(r, rs1, 70, "x31"),
+        16 => mop!(r, rs1, 96, "x28"),
+        17 => mop!(r, rs1, 96, "x29"),
+        18 => mop!(r, rs1, 96, "x30"),
+        19 => mop!(r, rs1, 96, "x31"),
+        20 => mop!(r, rs1, 98, "x28"),
+        21 => mop!(r, rs1, 98, "x29"),
+        22 => mop!(r, rs1, 98, "x30"),
+        23 => mop!(r, rs1, 98, "x31"),
+        24 => mop!(r, rs1, 100, "x28"),
+        25 => mop!(r, rs1, 100, "x29"),
+        26 => mop!(r, rs1, 100, "x30"),
+        27 => mop!(r, rs1, 100, "x31"),
+        28 => mop!(r, rs1, 102, "x28"),
+        29 => mop!(r, rs1, 102, "x29"),
+        30 => mop!(r, rs1, 102, "x30"),
+        31 => mop!(r, rs1, 102, "x31"),
+        _ => panic!("invalid MOP.R number"),
+    }
+}
+
+/// `MOP.RR.n` instruction wrapper (Zimop extension)
+///
+/// May-be-operation with two source registers. Unless redefined by another extension,
+/// `MOP.RR.n` only writes 0 to its destination register. Thus, this function returns 0.
+/// Future extensions (e.g., control-flow integrity) may redefine some of these instructions.
+///
+/// # Panics
+///
+/// This function panics if `n` is greater than 7.
+#[cfg(feature = "zimop")]
+#[inline]
+#[allow(unused_variables)]
+pub fn mop_rr(n: usize, rs1: usize, rs2: usize) -> usize {
+    match n {
+        0 => mop!(rr, rs1, rs2, 65),
+        1 => mop!(rr, rs1, rs2, 67),
+        2 => mop!(rr, rs1, rs2, 69),
+        3 => mop!(rr, rs1, rs2, 71),
+        4 => mop!(rr, rs1, rs2, 97),
+        5 => mop!(rr, rs1, rs2, 99),
+        6 => mop!(rr, rs1, rs2, 101),
+        7 => mop!(rr, rs1, rs2, 103),
+        _ => panic!("invalid MOP.RR number"),
+    }
+}
+
+/// `SSPUSH` instruction wrapper (Zicfiss extension)
+///
+/// Pushes `value` onto the shadow stack of the current privilege mode (encoded as `sspush t0`).
+/// If shadow stacks are not enabled for the current privilege mode (see `xenvcfg.SSE`),
+/// the instruction behaves as a `MOP.RR.7` and does nothing.
+///
+/// # Safety
+///
+/// The shadow stack is only written by `SSPUSH` and shadow stack AMOs, and read back by
+/// [`sspopchk`], which raises a software-check exception if the popped value does not match.
+/// Every `sspush` must be balanced by a `sspopchk` of the same value in the same context.
+/// Otherwise, later checks (e.g., of compiler-generated function epilogues) fail.
+///
+/// # Example
+///
+/// ```no_run
+/// # use riscv::asm::{sspopchk, sspush};
+/// let return_address = 0x8000_0000;
+/// unsafe {
+///     sspush(return_address);
+///     // ...
+///     sspopchk(return_address);
+/// }
+/// ```
+#[cfg(feature = "zicfiss")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn sspush(value: usize) {
+    match () {
+        // sspush t0
+        #[cfg(riscv)]
+        () => {
+            core::arch::asm!(".insn r 0x73, 0x4, 0x67, x0, x0, x5", in("t0") value, options(nostack))
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SSPOPCHK` instruction wrapper (Zicfiss extension)
+///
+/// Pops a value from the shadow stack of the current privilege mode and compares it with `expected`
+/// (encoded as `sspopchk t0`). On mismatch, it raises a software-check exception.
+/// If shadow stacks are not enabled for the current privilege mode (see `xenvcfg.SSE`),
+/// the instruction behaves as a `MOP.R.28` and does nothing.
+///
+/// # Safety
+///
+/// It must balance a previous [`sspush`] of the same value. See its documentation for more information.
+#[cfg(feature = "zicfiss")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn sspopchk(expected: usize) {
+    match () {
+        // sspopchk t0
+        #[cfg(riscv)]
+        () => {
+            core::arch::asm!(".insn r 0x73, 0x4, 0x66, x0, x5, x28", in("t0") expected, options(nostack))
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SSRDP` instruction wrapper (Zicfiss extension)
+///
+/// Returns the shadow stack pointer (i.e., the `ssp` CSR).
+/// If shadow stacks are not enabled for the current privilege mode (see `xenvcfg.SSE`),
+/// the instruction behaves as a `MOP.R.28` and returns 0.
+#[cfg(feature = "zicfiss")]
+#[inline]
+pub fn ssrdp() -> usize {
+    match () {
+        // ssrdp {0}
+        #[cfg(riscv)]
+        () => {
+            let rd: usize;
+            unsafe {
+                core::arch::asm!(".insn r 0x73, 0x4, 0x66, {0}, x0, x28", out(reg) rd, options(nomem, nostack))
+            };
+            rd
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
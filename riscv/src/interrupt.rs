@@ -182,6 +182,83 @@ pub mod supervisor {
     }
 }
 
+/// Resumable non-maskable interrupts (Smrnmi extension).
+pub mod nmi {
+    use crate::register::mnstatus;
+
+    /// Returns `true` if resumable non-maskable interrupts are enabled in the current hart.
+    #[inline]
+    pub fn is_enabled() -> bool {
+        mnstatus::read().nmie()
+    }
+
+    /// Enables resumable non-maskable interrupts in the current hart.
+    ///
+    /// Once enabled, RNMIs cannot be disabled by software. Hardware clears `mnstatus.NMIE`
+    /// when taking an RNMI, and `mnret` sets it again.
+    ///
+    /// # Safety
+    ///
+    /// A valid RNMI handler must be installed before calling this function.
+    #[inline]
+    pub unsafe fn enable() {
+        mnstatus::set_nmie()
+    }
+
+    /// Installs the RNMI handler at `handler_addr` and enables RNMIs in the current hart.
+    ///
+    /// The Smrnmi extension leaves the RNMI trap handler address implementation-defined:
+    /// some cores hardwire it, while others expose it through a custom CSR or a
+    /// memory-mapped register. Thus, `set_vector` receives `handler_addr` and must program it
+    /// in the location provided by the platform. Use a no-op closure if the vector is fixed.
+    /// Once `set_vector` returns, `mnstatus.NMIE` is set.
+    ///
+    /// # Safety
+    ///
+    /// - `handler_addr` must point to a valid RNMI handler that saves and restores
+    ///   `mnepc`, `mncause` and `mnstatus` as needed and returns with `mnret`.
+    /// - `set_vector` must program the RNMI vector of the current hart.
+    #[inline]
+    pub unsafe fn configure_nmi<F: FnOnce(usize)>(handler_addr: usize, set_vector: F) {
+        configure_nmi_with(handler_addr, set_vector, || enable());
+    }
+
+    /// Same as [`configure_nmi`], but using the provided function to set `mnstatus.NMIE`.
+    #[inline]
+    fn configure_nmi_with<F: FnOnce(usize), N: FnOnce()>(
+        handler_addr: usize,
+        set_vector: F,
+        set_nmie: N,
+    ) {
+        set_vector(handler_addr);
+        set_nmie();
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use core::cell::Cell;
+
+        #[test]
+        fn test_configure_nmi() {
+            let vector = Cell::new(None);
+            let nmie = Cell::new(false);
+            configure_nmi_with(
+                0x8000_0100,
+                |addr| {
+                    assert!(!nmie.get(), "NMIE set before installing the vector");
+                    vector.set(Some(addr));
+                },
+                || {
+                    assert_eq!(vector.get(), Some(0x8000_0100));
+                    nmie.set(true);
+                },
+            );
+            assert!(nmie.get());
+        }
+    }
+}
+
 #[cfg(not(feature = "s-mode"))]
 pub use machine::*;
 #[cfg(feature = "s-mode")]
@@ -31,14 +31,67 @@
 //! and may cause functional problems in systems where some interrupts must NOT be disabled
 //! or critical sections are managed as part of an RTOS. In these cases, you should use
 //! a target-specific implementation instead, typically provided by a HAL or RTOS crate.
+//!
+//! ## `zawrs`
+//!
+//! This feature enables the `asm::wrs_nto` and `asm::wrs_sto` wait-on-reservation-set
+//! instructions of the Zawrs extension. Only enable it on targets that implement Zawrs.
+//!
+//! ## `aia`
+//!
+//! This feature enables the `mvien`, `mvip`, `hvien`, and `hvip` virtual interrupt registers
+//! of the Advanced Interrupt Architecture (AIA). Only enable it on targets that implement AIA.
+//!
+//! ## `sstc`
+//!
+//! This feature enables the `stimecmp` and `stimecmph` registers of the Sstc extension,
+//! and `register::sip::clear_stimer`, which clears `sip.STIP` through `stimecmp`.
+//! Only enable it on targets that implement Sstc.
+//!
+//! ## `zimop`
+//!
+//! This feature enables the `asm::mop_r` and `asm::mop_rr` may-be-operation instructions
+//! of the Zimop extension. Only enable it on targets that implement Zimop.
+//!
+//! ## `zicbop`
+//!
+//! This feature enables the `asm::prefetch_i`, `asm::prefetch_r`, and `asm::prefetch_w`
+//! cache block prefetch hints of the Zicbop extension. They are encoded as `ORI` hints,
+//! so targets without Zicbop execute them as no-ops.
+//!
+//! ## `zicfiss`
+//!
+//! This feature enables the `ssp` register and the `asm::sspush`, `asm::sspopchk`, and `asm::ssrdp`
+//! shadow stack instructions of the Zicfiss extension. The instructions are encoded as may-be-operations,
+//! so they do nothing unless shadow stacks are enabled for the current privilege mode.
+//!
+//! ## `zicfilp`
+//!
+//! This feature enables the `asm::lpad` landing pad instruction of the Zicfilp extension.
+//! It is encoded as an `AUIPC` hint, so it does nothing unless landing pads are enabled for the current privilege mode.
+//!
+//! ## `test-mock`
+//!
+//! This feature allows injecting a deterministic sequence of `seed` CSR values with
+//! `register::seed::set_mock_entropy`, so that code built on the entropy source can be tested on the host.
+//! It also allows emulating the `dscratch0` and `dscratch1` debug registers in RAM with
+//! `register::dscratch0::set_mock` and `register::dscratch1::set_mock`.
+//! It requires the standard library.
 
 #![no_std]
 #![allow(clippy::missing_safety_doc)]
 
+#[cfg(feature = "test-mock")]
+extern crate std;
+
 pub mod asm;
 pub mod delay;
 pub mod interrupt;
+pub mod pmu;
 pub mod register;
+mod xlen;
+
+pub use self::xlen::{sign_extend, xlen_mask, zero_extend, XLEN};
 
 #[macro_use]
 mod macros;
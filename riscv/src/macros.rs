@@ -55,3 +55,36 @@ macro_rules! singleton {
         })
     };
 }
+
+/// Macro to detect at runtime whether a CSR is implemented.
+///
+/// It expands to a boolean expression that attempts to read the CSR with address `$csr`,
+/// and returns `false` if the read raised an illegal instruction exception.
+/// The CSR address must be a literal, as it is encoded in the instruction.
+///
+/// # Note
+///
+/// The trap handler must cooperate to detect the exception.
+/// See [`register::probe`](crate::register::probe) for the required handler setup.
+/// Without it, probing an unimplemented CSR does not return.
+///
+/// # Example
+///
+/// ```no_run
+/// // Sstc extension
+/// let has_stimecmp = riscv::probe_csr!(0x14D);
+/// ```
+#[macro_export]
+macro_rules! probe_csr {
+    ($csr:literal) => {
+        $crate::register::probe::probe_csr_with(|| match () {
+            #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+            () => unsafe {
+                core::arch::asm!(concat!("csrrs {0}, ", stringify!($csr), ", x0"), out(reg) _)
+            },
+
+            #[cfg(not(any(target_arch = "riscv32", target_arch = "riscv64")))]
+            () => unimplemented!(),
+        })
+    };
+}
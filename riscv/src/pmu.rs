@@ -0,0 +1,501 @@
+//! Sampling with hardware performance monitoring counters (Sscofpmf extension)
+//!
+//! A [`Pmu`] programs an `mhpmcounterX` to overflow after a given number of events selected
+//! by `mhpmeventX`. When the counter overflows, the hardware sets the `OF` bit of `mhpmeventX`,
+//! which is mirrored in `scountovf`, and raises a local counter overflow interrupt (LCOFI)
+//! if it is enabled in `mie`. The interrupt handler then uses [`Pmu::overflowed`] to find
+//! the counters that fired and [`Pmu::rearm`] to take the next sample:
+//!
+//! ```no_run
+//! use riscv::{pmu::Pmu, register::{mcause, mie, mip}};
+//!
+//! const EVENT: u64 = 0x1; // platform-specific event selector
+//! const PERIOD: u64 = 10_000;
+//!
+//! unsafe {
+//!     Pmu::new(3).start(EVENT, PERIOD);
+//!     mie::set_lcofie();
+//! }
+//!
+//! // in the trap handler
+//! if mcause::read().is_interrupt() && mcause::read().code() == Pmu::LCOFI {
+//!     for pmu in Pmu::overflowed() {
+//!         // record a sample for `pmu.counter()`
+//!         unsafe { pmu.rearm(PERIOD) };
+//!     }
+//!     unsafe { mip::clear_lcofip() };
+//! }
+//! ```
+//!
+//! # Note
+//!
+//! All the functions of this module access M-mode CSRs, so they must be called from M-mode.
+
+use crate::register::scountovf;
+
+/// Runs `$body` with the CSR modules of the performance monitoring counter `$index` in scope.
+macro_rules! hpm {
+    ($index:expr, |$counter:ident, $counterh:ident, $event:ident, $eventh:ident| $body:expr) => {
+        #[allow(unused_imports)]
+        match $index {
+            3 => {
+                use crate::register::{
+                    mhpmcounter3 as $counter, mhpmcounter3h as $counterh, mhpmevent3 as $event,
+                    mhpmevent3h as $eventh,
+                };
+                $body
+            }
+            4 => {
+                use crate::register::{
+                    mhpmcounter4 as $counter, mhpmcounter4h as $counterh, mhpmevent4 as $event,
+                    mhpmevent4h as $eventh,
+                };
+                $body
+            }
+            5 => {
+                use crate::register::{
+                    mhpmcounter5 as $counter, mhpmcounter5h as $counterh, mhpmevent5 as $event,
+                    mhpmevent5h as $eventh,
+                };
+                $body
+            }
+            6 => {
+                use crate::register::{
+                    mhpmcounter6 as $counter, mhpmcounter6h as $counterh, mhpmevent6 as $event,
+                    mhpmevent6h as $eventh,
+                };
+                $body
+            }
+            7 => {
+                use crate::register::{
+                    mhpmcounter7 as $counter, mhpmcounter7h as $counterh, mhpmevent7 as $event,
+                    mhpmevent7h as $eventh,
+                };
+                $body
+            }
+            8 => {
+                use crate::register::{
+                    mhpmcounter8 as $counter, mhpmcounter8h as $counterh, mhpmevent8 as $event,
+                    mhpmevent8h as $eventh,
+                };
+                $body
+            }
+            9 => {
+                use crate::register::{
+                    mhpmcounter9 as $counter, mhpmcounter9h as $counterh, mhpmevent9 as $event,
+                    mhpmevent9h as $eventh,
+                };
+                $body
+            }
+            10 => {
+                use crate::register::{
+                    mhpmcounter10 as $counter, mhpmcounter10h as $counterh, mhpmevent10 as $event,
+                    mhpmevent10h as $eventh,
+                };
+                $body
+            }
+            11 => {
+                use crate::register::{
+                    mhpmcounter11 as $counter, mhpmcounter11h as $counterh, mhpmevent11 as $event,
+                    mhpmevent11h as $eventh,
+                };
+                $body
+            }
+            12 => {
+                use crate::register::{
+                    mhpmcounter12 as $counter, mhpmcounter12h as $counterh, mhpmevent12 as $event,
+                    mhpmevent12h as $eventh,
+                };
+                $body
+            }
+            13 => {
+                use crate::register::{
+                    mhpmcounter13 as $counter, mhpmcounter13h as $counterh, mhpmevent13 as $event,
+                    mhpmevent13h as $eventh,
+                };
+                $body
+            }
+            14 => {
+                use crate::register::{
+                    mhpmcounter14 as $counter, mhpmcounter14h as $counterh, mhpmevent14 as $event,
+                    mhpmevent14h as $eventh,
+                };
+                $body
+            }
+            15 => {
+                use crate::register::{
+                    mhpmcounter15 as $counter, mhpmcounter15h as $counterh, mhpmevent15 as $event,
+                    mhpmevent15h as $eventh,
+                };
+                $body
+            }
+            16 => {
+                use crate::register::{
+                    mhpmcounter16 as $counter, mhpmcounter16h as $counterh, mhpmevent16 as $event,
+                    mhpmevent16h as $eventh,
+                };
+                $body
+            }
+            17 => {
+                use crate::register::{
+                    mhpmcounter17 as $counter, mhpmcounter17h as $counterh, mhpmevent17 as $event,
+                    mhpmevent17h as $eventh,
+                };
+                $body
+            }
+            18 => {
+                use crate::register::{
+                    mhpmcounter18 as $counter, mhpmcounter18h as $counterh, mhpmevent18 as $event,
+                    mhpmevent18h as $eventh,
+                };
+                $body
+            }
+            19 => {
+                use crate::register::{
+                    mhpmcounter19 as $counter, mhpmcounter19h as $counterh, mhpmevent19 as $event,
+                    mhpmevent19h as $eventh,
+                };
+                $body
+            }
+            20 => {
+                use crate::register::{
+                    mhpmcounter20 as $counter, mhpmcounter20h as $counterh, mhpmevent20 as $event,
+                    mhpmevent20h as $eventh,
+                };
+                $body
+            }
+            21 => {
+                use crate::register::{
+                    mhpmcounter21 as $counter, mhpmcounter21h as $counterh, mhpmevent21 as $event,
+                    mhpmevent21h as $eventh,
+                };
+                $body
+            }
+            22 => {
+                use crate::register::{
+                    mhpmcounter22 as $counter, mhpmcounter22h as $counterh, mhpmevent22 as $event,
+                    mhpmevent22h as $eventh,
+                };
+                $body
+            }
+            23 => {
+                use crate::register::{
+                    mhpmcounter23 as $counter, mhpmcounter23h as $counterh, mhpmevent23 as $event,
+                    mhpmevent23h as $eventh,
+                };
+                $body
+            }
+            24 => {
+                use crate::register::{
+                    mhpmcounter24 as $counter, mhpmcounter24h as $counterh, mhpmevent24 as $event,
+                    mhpmevent24h as $eventh,
+                };
+                $body
+            }
+            25 => {
+                use crate::register::{
+                    mhpmcounter25 as $counter, mhpmcounter25h as $counterh, mhpmevent25 as $event,
+                    mhpmevent25h as $eventh,
+                };
+                $body
+            }
+            26 => {
+                use crate::register::{
+                    mhpmcounter26 as $counter, mhpmcounter26h as $counterh, mhpmevent26 as $event,
+                    mhpmevent26h as $eventh,
+                };
+                $body
+            }
+            27 => {
+                use crate::register::{
+                    mhpmcounter27 as $counter, mhpmcounter27h as $counterh, mhpmevent27 as $event,
+                    mhpmevent27h as $eventh,
+                };
+                $body
+            }
+            28 => {
+                use crate::register::{
+                    mhpmcounter28 as $counter, mhpmcounter28h as $counterh, mhpmevent28 as $event,
+                    mhpmevent28h as $eventh,
+                };
+                $body
+            }
+            29 => {
+                use crate::register::{
+                    mhpmcounter29 as $counter, mhpmcounter29h as $counterh, mhpmevent29 as $event,
+                    mhpmevent29h as $eventh,
+                };
+                $body
+            }
+            30 => {
+                use crate::register::{
+                    mhpmcounter30 as $counter, mhpmcounter30h as $counterh, mhpmevent30 as $event,
+                    mhpmevent30h as $eventh,
+                };
+                $body
+            }
+            31 => {
+                use crate::register::{
+                    mhpmcounter31 as $counter, mhpmcounter31h as $counterh, mhpmevent31 as $event,
+                    mhpmevent31h as $eventh,
+                };
+                $body
+            }
+            _ => unreachable!(),
+        }
+    };
+}
+
+/// Access to the CSRs used by [`Pmu`].
+trait Csrs {
+    /// Reads `mhpmeventX` (and `mhpmeventXh` in RISCV-32).
+    fn read_event(&mut self, index: usize) -> u64;
+    /// Writes `mhpmeventX` (and `mhpmeventXh` in RISCV-32).
+    fn write_event(&mut self, index: usize, value: u64);
+    /// Writes `mhpmcounterX` (and `mhpmcounterXh` in RISCV-32).
+    fn write_counter(&mut self, index: usize, value: u64);
+    /// Reads `scountovf`.
+    fn read_scountovf(&mut self) -> usize;
+}
+
+/// The actual CSRs of the HART.
+struct Hart;
+
+impl Csrs for Hart {
+    #[inline]
+    fn read_event(&mut self, index: usize) -> u64 {
+        hpm!(index, |_c, _ch, event, eventh| match () {
+            #[cfg(riscv32)]
+            () => ((eventh::read() as u64) << 32) | event::read() as u64,
+            #[cfg(not(riscv32))]
+            () => event::read() as u64,
+        })
+    }
+
+    #[inline]
+    fn write_event(&mut self, index: usize, value: u64) {
+        hpm!(index, |_c, _ch, event, eventh| {
+            #[cfg(riscv32)]
+            eventh::write((value >> 32) as usize);
+            event::write(value as usize);
+        })
+    }
+
+    #[inline]
+    fn write_counter(&mut self, index: usize, value: u64) {
+        hpm!(index, |counter, counterh, _e, _eh| {
+            #[cfg(riscv32)]
+            counterh::write((value >> 32) as usize);
+            counter::write(value as usize);
+        })
+    }
+
+    #[inline]
+    fn read_scountovf(&mut self) -> usize {
+        scountovf::read().bits()
+    }
+}
+
+/// Sampling helper for the performance monitoring counter `mhpmcounterX`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pmu {
+    index: usize,
+}
+
+impl Pmu {
+    /// Exception code of the local counter overflow interrupt (LCOFI) in `mcause` and `scause`.
+    /// It is also the bit of the interrupt in `mie`/`mip` and `sie`/`sip`.
+    pub const LCOFI: usize = 13;
+
+    /// Overflow status and interrupt disable bit (`OF`) of `mhpmeventX`.
+    /// In RISCV-32, it is bit 31 of `mhpmeventXh`.
+    pub const OF: u64 = 1 << 63;
+
+    /// Creates a sampling helper for `mhpmcounter[index]`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not in the range `3..32`.
+    #[inline]
+    pub const fn new(index: usize) -> Self {
+        assert!(index >= 3 && index < 32);
+        Self { index }
+    }
+
+    /// Returns the index of the performance monitoring counter.
+    #[inline]
+    pub const fn counter(self) -> usize {
+        self.index
+    }
+
+    /// Selects `event` and programs the counter to overflow after `period` events.
+    ///
+    /// The `OF` bit of `event` is cleared, so the counter raises an LCOFI when it overflows.
+    /// The rest of `event` (i.e., the event selector and the mode inhibit bits) is platform-specific.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is zero.
+    #[inline]
+    pub unsafe fn start(self, event: u64, period: u64) {
+        self.start_with(&mut Hart, event, period);
+    }
+
+    /// Programs the counter to overflow after `period` events and clears its `OF` bit,
+    /// so that it can raise a new LCOFI. The selected event is kept.
+    ///
+    /// # Note
+    ///
+    /// It does not clear `mip.LCOFIP`, which is shared by all the counters.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is zero.
+    #[inline]
+    pub unsafe fn rearm(self, period: u64) {
+        self.rearm_with(&mut Hart, period);
+    }
+
+    /// Stops counting events by selecting no event.
+    #[inline]
+    pub unsafe fn stop(self) {
+        Hart.write_event(self.index, 0);
+    }
+
+    /// Returns `true` if the counter has overflowed since it was started or rearmed.
+    #[inline]
+    pub fn has_overflowed(self) -> bool {
+        self.has_overflowed_with(&mut Hart)
+    }
+
+    /// Returns an iterator over the counters that have overflowed, as reported by `scountovf`.
+    ///
+    /// Call it when handling an LCOFI to find the counters that fired.
+    #[inline]
+    pub fn overflowed() -> impl Iterator<Item = Self> {
+        Self::overflowed_with(&mut Hart)
+    }
+
+    /// Same as [`Pmu::start`], but using the provided CSRs.
+    #[inline]
+    fn start_with<C: Csrs>(self, csrs: &mut C, event: u64, period: u64) {
+        assert!(period > 0);
+        // stop counting while the counter is updated
+        csrs.write_event(self.index, 0);
+        csrs.write_counter(self.index, period.wrapping_neg());
+        csrs.write_event(self.index, event & !Self::OF);
+    }
+
+    /// Same as [`Pmu::rearm`], but using the provided CSRs.
+    #[inline]
+    fn rearm_with<C: Csrs>(self, csrs: &mut C, period: u64) {
+        let event = csrs.read_event(self.index);
+        self.start_with(csrs, event, period);
+    }
+
+    /// Same as [`Pmu::has_overflowed`], but using the provided CSRs.
+    #[inline]
+    fn has_overflowed_with<C: Csrs>(self, csrs: &mut C) -> bool {
+        scountovf::Scountovf::from(csrs.read_scountovf()).of(self.index)
+    }
+
+    /// Same as [`Pmu::overflowed`], but using the provided CSRs.
+    #[inline]
+    fn overflowed_with<C: Csrs>(csrs: &mut C) -> impl Iterator<Item = Self> {
+        scountovf::Scountovf::from(csrs.read_scountovf())
+            .overflowed()
+            .map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Performance monitoring counters emulated in RAM.
+    #[derive(Default)]
+    struct MockCsrs {
+        events: [u64; 32],
+        counters: [u64; 32],
+    }
+
+    impl MockCsrs {
+        /// Emulates `n` occurrences of the event selected by counter `index`.
+        fn count(&mut self, index: usize, n: u64) {
+            if self.events[index] & !Pmu::OF == 0 {
+                return; // no event selected
+            }
+            let (value, overflow) = self.counters[index].overflowing_add(n);
+            self.counters[index] = value;
+            if overflow {
+                self.events[index] |= Pmu::OF;
+            }
+        }
+    }
+
+    impl Csrs for MockCsrs {
+        fn read_event(&mut self, index: usize) -> u64 {
+            self.events[index]
+        }
+
+        fn write_event(&mut self, index: usize, value: u64) {
+            self.events[index] = value;
+        }
+
+        fn write_counter(&mut self, index: usize, value: u64) {
+            self.counters[index] = value;
+        }
+
+        fn read_scountovf(&mut self) -> usize {
+            // scountovf mirrors the OF bits of the counters
+            (3..32)
+                .filter(|&index| self.events[index] & Pmu::OF != 0)
+                .fold(0, |bits, index| bits | (1 << index))
+        }
+    }
+
+    #[test]
+    fn test_pmu_overflow() {
+        const EVENT: u64 = 0x42;
+        let mut csrs = MockCsrs::default();
+        let (pmu4, pmu7) = (Pmu::new(4), Pmu::new(7));
+
+        pmu4.start_with(&mut csrs, EVENT | Pmu::OF, 100);
+        pmu7.start_with(&mut csrs, EVENT, 1_000);
+        // the OF bit is cleared to enable the overflow interrupt
+        assert_eq!(csrs.events[4], EVENT);
+        assert_eq!(csrs.counters[4], u64::MAX - 99);
+
+        csrs.count(4, 99);
+        csrs.count(7, 500);
+        assert!(!pmu4.has_overflowed_with(&mut csrs));
+        assert_eq!(Pmu::overflowed_with(&mut csrs).count(), 0);
+
+        // the 100th event overflows the counter
+        csrs.count(4, 1);
+        assert!(pmu4.has_overflowed_with(&mut csrs));
+        assert!(!pmu7.has_overflowed_with(&mut csrs));
+        assert!(Pmu::overflowed_with(&mut csrs).eq([pmu4]));
+
+        // rearming keeps the event and clears the overflow
+        pmu4.rearm_with(&mut csrs, 10);
+        assert_eq!(csrs.events[4], EVENT);
+        assert_eq!(Pmu::overflowed_with(&mut csrs).count(), 0);
+
+        csrs.count(4, 10);
+        csrs.count(7, 500);
+        assert!(Pmu::overflowed_with(&mut csrs).map(Pmu::counter).eq([4, 7]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pmu_invalid_counter() {
+        Pmu::new(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pmu_invalid_period() {
+        Pmu::new(3).start_with(&mut MockCsrs::default(), 0x42, 0);
+    }
+}
@@ -9,7 +9,18 @@
 //! - mcycleh
 //! - minstreth
 //! - mhpmcounter<3-31>h
+//! - mhpmevent<3-31>h
 //! - mstatush
+//! - mstateen<0-3>h
+//! - henvcfgh
+//! - menvcfgh
+//!
+//! # On Read-Modify-Write Accesses
+//!
+//! The `set_*` and `clear_*` functions of each register are implemented with the `csrrs` and
+//! `csrrc` instructions. They modify only the requested bits in a single instruction, so bits that
+//! the hardware may change concurrently (e.g., pending bits in `mip`) are never clobbered.
+//! Prefer them over a `read` followed by a `write` when modifying such registers.
 //!
 //! # On Floating-Point CSRs
 //!
@@ -39,6 +50,28 @@
 #[macro_use]
 mod macros;
 
+mod interrupt_flags;
+pub use self::interrupt_flags::{InterruptEnableCsr, InterruptFlags};
+
+// User Table Jump (Zcmt)
+pub mod jvt;
+
+// User Shadow Stack Pointer (Zicfiss)
+#[cfg(feature = "zicfiss")]
+pub mod ssp;
+
+// User Entropy Source (Zkr)
+pub mod seed;
+
+// User Vector (V)
+pub mod vcsr;
+mod vector_context;
+pub mod vl;
+pub mod vlenb;
+pub mod vstart;
+pub mod vtype;
+pub use self::vector_context::VectorContext;
+
 // User Counter/Timers
 pub mod cycle;
 pub mod cycleh;
@@ -62,11 +95,67 @@ pub mod sip;
 pub mod sscratch;
 pub mod stval;
 
+// Supervisor Timer Compare (Sstc)
+#[cfg(feature = "sstc")]
+pub mod stimecmp;
+#[cfg(feature = "sstc")]
+pub mod stimecmph;
+
+// Supervisor State Enable (Smstateen)
+pub mod sstateen0;
+mod sstateenx;
+pub use self::sstateenx::*;
+
+// Supervisor Top Interrupt (AIA)
+pub mod stopi;
+
+// Supervisor Indirect CSR Access (Sscsrind)
+pub mod sireg;
+pub mod siselect;
+
+// Supervisor Configuration
+pub mod senvcfg;
+
 // Supervisor Protection and Translation
 pub mod satp;
 
+// Supervisor Counter Overflow (Sscofpmf)
+pub mod scountovf;
+
+// Supervisor Counter Inhibit (Ssccfg)
+pub mod scountinhibit;
+
+// Supervisor Resource Management (Ssqosid)
+pub mod srmcfg;
+
+// Hypervisor Trap Setup
+pub mod hcounteren;
+pub mod hedeleg;
+pub mod hgeie;
+pub mod hideleg;
+pub mod hstatus;
+
+// Hypervisor Trap Handling
+pub mod hgeip;
+
+// Hypervisor Configuration
+pub mod henvcfg;
+pub mod henvcfgh;
+
+// Virtual Supervisor Registers
+pub mod vsatp;
+pub mod vscause;
+pub mod vsepc;
+pub mod vsie;
+pub mod vsip;
+pub mod vsscratch;
+pub mod vsstatus;
+pub mod vstval;
+pub mod vstvec;
+
 // Machine Information Registers
 pub mod marchid;
+pub mod mconfigptr;
 pub mod mhartid;
 pub mod mimpid;
 pub mod mvendorid;
@@ -86,7 +175,29 @@ pub mod mcause;
 pub mod mepc;
 pub mod mip;
 pub mod mscratch;
+pub mod mtinst;
 pub mod mtval;
+pub mod mtval2;
+mod trap_cause;
+pub use self::trap_cause::{TrapCause, TrapKind};
+
+// Machine Configuration
+pub mod menvcfg;
+pub mod menvcfgh;
+pub mod mseccfg;
+
+// Machine Resumable NMI (Smrnmi)
+pub mod mncause;
+pub mod mnepc;
+pub mod mnscratch;
+pub mod mnstatus;
+
+// Machine Top Interrupt (AIA)
+pub mod mtopi;
+
+// Machine Indirect CSR Access (Smcsrind)
+pub mod mireg;
+pub mod miselect;
 
 // Machine Protection and Translation
 mod pmpcfgx;
@@ -106,6 +217,44 @@ pub mod minstreth;
 mod mhpmeventx;
 pub use self::mhpmeventx::*;
 
-// TODO: Debug/Trace Registers (shared with Debug Mode)
+// Machine Counter Configuration (Smcntrpmf)
+pub mod mcyclecfg;
+pub mod mcyclecfgh;
+pub mod minstretcfg;
+pub mod minstretcfgh;
+
+// Machine State Enable (Smstateen)
+pub mod mstateen0;
+pub mod mstateen0h;
+mod mstateenx;
+pub use self::mstateenx::*;
+
+// Virtual Interrupts (AIA)
+#[cfg(feature = "aia")]
+pub mod hvien;
+#[cfg(feature = "aia")]
+pub mod hvip;
+#[cfg(feature = "aia")]
+pub mod mvien;
+#[cfg(feature = "aia")]
+pub mod mvip;
+
+// Debug/Trace Registers (shared with Debug Mode)
+pub mod mcontext;
+pub mod scontext;
+
+// Debug Mode Registers
+// TODO: dcsr, dpc
+pub mod dscratch0;
+pub mod dscratch1;
+
+// Effective pointer masking mode (Smmpm/Smnpm/Ssnpm)
+#[cfg(target_pointer_width = "64")]
+mod pointer_masking;
+#[cfg(all(target_pointer_width = "64", not(feature = "s-mode")))]
+pub use self::pointer_masking::current_pointer_masking;
+#[cfg(target_pointer_width = "64")]
+pub use self::pointer_masking::pointer_masking;
 
-// TODO: Debug Mode Registers
+// Runtime CSR presence detection
+pub mod probe;
@@ -0,0 +1,83 @@
+//! dscratch0 register (Debug Mode)
+//!
+//! `dscratch0` is only accessible in Debug Mode. With the `test-mock` feature, it can be emulated
+//! in RAM with [`set_mock`], so that code that uses it can be tested on the host.
+
+read_csr!(0x7B2);
+write_csr!(0x7B2);
+
+#[cfg(feature = "test-mock")]
+std::thread_local! {
+    /// Emulated value of the register in the current thread, if any.
+    static MOCK: core::cell::Cell<Option<usize>> = const { core::cell::Cell::new(None) };
+}
+
+/// Emulates the register in RAM for the current thread, starting with `bits`.
+/// Subsequent calls to [`read`] and [`write`] access the emulated register instead of the CSR.
+#[cfg(feature = "test-mock")]
+#[inline]
+pub fn set_mock(bits: usize) {
+    MOCK.with(|mock| mock.set(Some(bits)));
+}
+
+/// Stops emulating the register in the current thread.
+#[cfg(feature = "test-mock")]
+#[inline]
+pub fn clear_mock() {
+    MOCK.with(|mock| mock.set(None));
+}
+
+/// Reads the CSR
+///
+/// If the `test-mock` feature is enabled and the register is emulated with [`set_mock`],
+/// it reads the emulated register instead.
+#[inline]
+pub fn read() -> usize {
+    #[cfg(feature = "test-mock")]
+    if let Some(bits) = MOCK.with(|mock| mock.get()) {
+        return bits;
+    }
+    unsafe { _read() }
+}
+
+/// Writes the CSR
+///
+/// If the `test-mock` feature is enabled and the register is emulated with [`set_mock`],
+/// it writes the emulated register instead.
+#[inline]
+pub fn write(bits: usize) {
+    #[cfg(feature = "test-mock")]
+    if MOCK.with(|mock| mock.get()).is_some() {
+        MOCK.with(|mock| mock.set(Some(bits)));
+        return;
+    }
+    unsafe { _write(bits) }
+}
+
+#[cfg(all(test, feature = "test-mock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dscratch0_round_trip() {
+        set_mock(0);
+        assert_eq!(read(), 0);
+        for bits in [0xDEAD_BEEF, usize::MAX, 0] {
+            write(bits);
+            assert_eq!(read(), bits);
+        }
+        clear_mock();
+
+        // each thread emulates its own register
+        set_mock(1);
+        std::thread::spawn(|| {
+            set_mock(2);
+            write(3);
+            assert_eq!(read(), 3);
+        })
+        .join()
+        .unwrap();
+        assert_eq!(read(), 1);
+        clear_mock();
+    }
+}
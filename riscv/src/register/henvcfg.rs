@@ -0,0 +1,315 @@
+//! henvcfg register (Hypervisor extension)
+
+/// henvcfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Henvcfg {
+    bits: usize,
+}
+
+impl From<usize> for Henvcfg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+/// Cache block invalidate instruction behavior
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CBIE {
+    /// `cbo.inval` raises an illegal instruction exception
+    IllegalInstruction = 0b00,
+    /// `cbo.inval` performs a flush operation
+    Flush = 0b01,
+    /// `cbo.inval` performs an invalidate operation
+    Invalidate = 0b11,
+}
+
+/// Pointer masking mode (Ssnpm/Smnpm extensions)
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PointerMaskingMode {
+    /// Pointer masking is disabled (PMLEN = 0)
+    Disabled = 0b00,
+    /// The upper 7 bits of addresses are ignored (PMLEN = 7)
+    Bits7 = 0b10,
+    /// The upper 16 bits of addresses are ignored (PMLEN = 16)
+    Bits16 = 0b11,
+}
+
+impl PointerMaskingMode {
+    /// Returns the number of masked upper address bits (PMLEN)
+    #[inline]
+    pub const fn pmlen(self) -> usize {
+        match self {
+            Self::Disabled => 0,
+            Self::Bits7 => 7,
+            Self::Bits16 => 16,
+        }
+    }
+
+    /// Returns the effective address of `ptr` under this masking mode.
+    ///
+    /// The upper PMLEN bits are replaced by the sign extension of bit `XLEN - PMLEN - 1`,
+    /// as done for virtual addresses. Physical addresses are zero-extended instead.
+    #[inline]
+    pub const fn masked_pointer(self, ptr: usize) -> usize {
+        crate::sign_extend(ptr, crate::XLEN - self.pmlen()) as usize
+    }
+}
+
+/// Update scheme of the A/D bits of page table entries (Svade/Svadu extensions)
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AdUpdate {
+    /// Svade: a page fault is raised when a PTE is accessed with A clear,
+    /// or written with D clear. Software must set the A/D bits.
+    Software = 0,
+    /// Svadu: the MMU sets the A/D bits of PTEs in hardware.
+    Hardware = 1,
+}
+
+impl AdUpdate {
+    /// Returns the update scheme selected by an ADUE bit.
+    #[inline]
+    pub const fn from_adue(adue: bool) -> Self {
+        match adue {
+            false => Self::Software,
+            true => Self::Hardware,
+        }
+    }
+}
+
+impl Henvcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Fence of I/O implies Memory
+    #[inline]
+    pub fn fiom(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// Landing Pad Enable (Zicfilp extension)
+    #[inline]
+    pub fn lpe(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Cache Block Invalidate instruction Enable (Zicbom extension)
+    #[inline]
+    pub fn cbie(&self) -> Option<CBIE> {
+        match (self.bits >> 4) & 0x3 {
+            0b00 => Some(CBIE::IllegalInstruction),
+            0b01 => Some(CBIE::Flush),
+            0b11 => Some(CBIE::Invalidate),
+            _ => None,
+        }
+    }
+
+    /// Cache Block Clean and Flush instruction Enable (Zicbom extension)
+    #[inline]
+    pub fn cbcfe(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Cache Block Zero instruction Enable (Zicboz extension)
+    #[inline]
+    pub fn cbze(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+
+    /// Pointer Masking Mode for VS-mode (Ssnpm extension)
+    ///
+    /// Pointer masking is only supported in RISCV-64.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn pmm(&self) -> Option<PointerMaskingMode> {
+        match (self.bits >> 32) & 0x3 {
+            0b00 => Some(PointerMaskingMode::Disabled),
+            0b10 => Some(PointerMaskingMode::Bits7),
+            0b11 => Some(PointerMaskingMode::Bits16),
+            _ => None,
+        }
+    }
+
+    /// Hardware A/D bit updating Enable (Svadu extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::henvcfgh`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn adue(&self) -> bool {
+        self.bits & (1 << 61) != 0
+    }
+
+    /// Returns whether the A/D bits of VS-stage PTEs are updated by the MMU or by software.
+    ///
+    /// If the Svadu extension is not implemented, ADUE is read-only zero and
+    /// this returns [`AdUpdate::Software`]: page table code must set the A/D bits itself.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn ad_update(&self) -> AdUpdate {
+        AdUpdate::from_adue(self.adue())
+    }
+
+    /// Page-Based Memory Types Enable (Svpbmt extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::henvcfgh`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn pbmte(&self) -> bool {
+        self.bits & (1 << 62) != 0
+    }
+
+    /// VS-mode timer compare Enable (Sstc extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::henvcfgh`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn stce(&self) -> bool {
+        self.bits & (1 << 63) != 0
+    }
+}
+
+read_csr_as!(Henvcfg, 0x60A);
+write_csr!(0x60A);
+set!(0x60A);
+clear!(0x60A);
+
+set_clear_csr!(
+    /// Fence of I/O implies Memory
+    , set_fiom, clear_fiom, 1 << 0);
+set_clear_csr!(
+    /// Landing Pad Enable
+    , set_lpe, clear_lpe, 1 << 2);
+set_clear_csr!(
+    /// Cache Block Clean and Flush instruction Enable
+    , set_cbcfe, clear_cbcfe, 1 << 6);
+set_clear_csr!(
+    /// Cache Block Zero instruction Enable
+    , set_cbze, clear_cbze, 1 << 7);
+set_clear_csr!(
+    /// Hardware A/D bit updating Enable
+    #[cfg(target_pointer_width = "64")]
+    , set_adue, clear_adue, 1 << 61);
+set_clear_csr!(
+    /// Page-Based Memory Types Enable
+    #[cfg(target_pointer_width = "64")]
+    , set_pbmte, clear_pbmte, 1 << 62);
+set_clear_csr!(
+    /// VS-mode timer compare Enable
+    #[cfg(target_pointer_width = "64")]
+    , set_stce, clear_stce, 1 << 63);
+
+/// Cache Block Invalidate instruction Enable
+#[inline]
+pub unsafe fn set_cbie(cbie: CBIE) {
+    let mut value = _read();
+    value &= !(0x3 << 4); // clear previous value
+    value |= (cbie as usize) << 4;
+    _write(value);
+}
+
+/// Pointer Masking Mode
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn set_pmm(pmm: PointerMaskingMode) {
+    let mut value = _read();
+    value &= !(0x3 << 32); // clear previous value
+    value |= (pmm as usize) << 32;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_henvcfg() {
+        let henvcfg = Henvcfg::from(0);
+        assert!(!henvcfg.fiom());
+        assert_eq!(henvcfg.cbie(), Some(CBIE::IllegalInstruction));
+        assert!(!henvcfg.cbcfe());
+        assert!(!henvcfg.cbze());
+
+        let henvcfg = Henvcfg::from((1 << 0) | (0b11 << 4) | (1 << 7));
+        assert!(henvcfg.fiom());
+        assert_eq!(henvcfg.cbie(), Some(CBIE::Invalidate));
+        assert!(!henvcfg.cbcfe());
+        assert!(henvcfg.cbze());
+
+        assert_eq!(Henvcfg::from(0b10 << 4).cbie(), None);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let henvcfg = Henvcfg::from(1 << 63);
+            assert!(henvcfg.stce());
+            assert!(!henvcfg.pbmte());
+            assert!(!henvcfg.adue());
+            assert_eq!(henvcfg.ad_update(), AdUpdate::Software);
+
+            // toggling ADUE switches between Svade and Svadu
+            let mut bits = henvcfg.bits() | (1 << 61);
+            let henvcfg = Henvcfg::from(bits);
+            assert!(henvcfg.adue());
+            assert_eq!(henvcfg.ad_update(), AdUpdate::Hardware);
+            assert!(henvcfg.stce());
+            bits &= !(1 << 61);
+            assert!(!Henvcfg::from(bits).adue());
+            assert_eq!(Henvcfg::from(bits).ad_update(), AdUpdate::Software);
+        }
+
+        // Zicfilp landing pads
+        let mut bits = 0b11 << 4;
+        assert!(!Henvcfg::from(bits).lpe());
+        bits |= 1 << 2;
+        let henvcfg = Henvcfg::from(bits);
+        assert!(henvcfg.lpe());
+        assert_eq!(henvcfg.cbie(), Some(CBIE::Invalidate));
+        bits &= !(1 << 2);
+        assert!(!Henvcfg::from(bits).lpe());
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_henvcfg_pmm() {
+        assert_eq!(Henvcfg::from(0).pmm(), Some(PointerMaskingMode::Disabled));
+        assert_eq!(Henvcfg::from(0b01 << 32).pmm(), None);
+        assert_eq!(
+            Henvcfg::from(0b10 << 32).pmm(),
+            Some(PointerMaskingMode::Bits7)
+        );
+        assert_eq!(
+            Henvcfg::from(0b11 << 32).pmm(),
+            Some(PointerMaskingMode::Bits16)
+        );
+        // other fields do not leak into PMM
+        assert_eq!(
+            Henvcfg::from(!(0x3 << 32)).pmm(),
+            Some(PointerMaskingMode::Disabled)
+        );
+    }
+
+    #[test]
+    fn test_masked_pointer() {
+        assert_eq!(PointerMaskingMode::Disabled.pmlen(), 0);
+        assert_eq!(PointerMaskingMode::Bits7.pmlen(), 7);
+        assert_eq!(PointerMaskingMode::Bits16.pmlen(), 16);
+
+        let tagged = (0x5B << (crate::XLEN - 8)) | 0x1234;
+        assert_eq!(PointerMaskingMode::Disabled.masked_pointer(tagged), tagged);
+        assert_eq!(PointerMaskingMode::Bits16.masked_pointer(tagged), 0x1234);
+        // bit XLEN - 8 is set, so Bits7 sign-extends it into the masked bits
+        assert_eq!(
+            PointerMaskingMode::Bits7.masked_pointer(tagged),
+            (0x7F << (crate::XLEN - 7)) | (1 << (crate::XLEN - 8)) | 0x1234
+        );
+
+        let kernel = usize::MAX << (crate::XLEN - 17);
+        assert_eq!(PointerMaskingMode::Bits16.masked_pointer(kernel), kernel);
+        assert_eq!(
+            PointerMaskingMode::Bits16.masked_pointer(kernel & !(1 << (crate::XLEN - 1))),
+            kernel
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! henvcfgh register (Hypervisor extension, RISCV-32 only)
+
+pub use super::henvcfg::AdUpdate;
+
+/// henvcfgh register
+#[derive(Clone, Copy, Debug)]
+pub struct Henvcfgh {
+    bits: usize,
+}
+
+impl From<usize> for Henvcfgh {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Henvcfgh {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Hardware A/D bit updating Enable (Svadu extension)
+    #[inline]
+    pub fn adue(&self) -> bool {
+        self.bits & (1 << 29) != 0
+    }
+
+    /// Returns whether the A/D bits of VS-stage PTEs are updated by the MMU or by software.
+    ///
+    /// If the Svadu extension is not implemented, ADUE is read-only zero and
+    /// this returns [`AdUpdate::Software`]: page table code must set the A/D bits itself.
+    #[inline]
+    pub fn ad_update(&self) -> AdUpdate {
+        AdUpdate::from_adue(self.adue())
+    }
+
+    /// Page-Based Memory Types Enable (Svpbmt extension)
+    #[inline]
+    pub fn pbmte(&self) -> bool {
+        self.bits & (1 << 30) != 0
+    }
+
+    /// VS-mode timer compare Enable (Sstc extension)
+    #[inline]
+    pub fn stce(&self) -> bool {
+        self.bits & (1 << 31) != 0
+    }
+}
+
+read_csr_as_rv32!(Henvcfgh, 0x61A);
+write_csr_rv32!(0x61A);
+set_rv32!(0x61A);
+clear_rv32!(0x61A);
+
+set_clear_csr!(
+    /// Hardware A/D bit updating Enable
+    , set_adue, clear_adue, 1 << 29);
+set_clear_csr!(
+    /// Page-Based Memory Types Enable
+    , set_pbmte, clear_pbmte, 1 << 30);
+set_clear_csr!(
+    /// VS-mode timer compare Enable
+    , set_stce, clear_stce, 1 << 31);
@@ -0,0 +1,76 @@
+//! hgeie register (Hypervisor extension)
+
+/// hgeie register
+///
+/// Bit `i` enables the guest external interrupts of guest `i` (bits 1-GEILEN).
+#[derive(Clone, Copy, Debug)]
+pub struct Hgeie {
+    bits: usize,
+}
+
+impl From<usize> for Hgeie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hgeie {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Guest External Interrupt Enable (bits 1-XLEN)
+    #[inline]
+    pub fn guest(&self, index: usize) -> bool {
+        assert!((1..usize::BITS as usize).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+}
+
+read_csr_as!(Hgeie, 0x607);
+write_csr!(0x607);
+set!(0x607);
+clear!(0x607);
+
+/// Guest External Interrupt Enable (bits 1-XLEN)
+#[inline]
+pub unsafe fn set_guest(index: usize) {
+    assert!((1..usize::BITS as usize).contains(&index));
+    _set(1 << index);
+}
+
+/// Guest External Interrupt Enable (bits 1-XLEN)
+#[inline]
+pub unsafe fn clear_guest(index: usize) {
+    assert!((1..usize::BITS as usize).contains(&index));
+    _clear(1 << index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hgeie() {
+        let mut bits = 0;
+        assert!(!Hgeie::from(bits).guest(3));
+
+        bits |= 1 << 3;
+        let hgeie = Hgeie::from(bits);
+        assert!(hgeie.guest(3));
+        assert!(!hgeie.guest(2));
+        assert!(!hgeie.guest(4));
+
+        bits &= !(1 << 3);
+        assert!(!Hgeie::from(bits).guest(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hgeie_invalid_guest() {
+        Hgeie::from(usize::MAX).guest(0);
+    }
+}
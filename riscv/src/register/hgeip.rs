@@ -0,0 +1,64 @@
+//! hgeip register (Hypervisor extension)
+
+/// hgeip register
+///
+/// Bit `i` indicates a pending guest external interrupt for guest `i` (bits 1-GEILEN).
+#[derive(Clone, Copy, Debug)]
+pub struct Hgeip {
+    bits: usize,
+}
+
+impl From<usize> for Hgeip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hgeip {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Guest External Interrupt Pending (bits 1-XLEN)
+    #[inline]
+    pub fn guest(&self, index: usize) -> bool {
+        assert!((1..usize::BITS as usize).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+
+    /// Returns an iterator over the indices of the guests with a pending external interrupt
+    #[inline]
+    pub fn pending(&self) -> impl Iterator<Item = usize> {
+        let bits = self.bits;
+        (1..usize::BITS as usize).filter(move |&index| bits & (1 << index) != 0)
+    }
+}
+
+read_csr_as!(Hgeip, 0xE12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hgeip() {
+        let hgeip = Hgeip::from(0);
+        assert_eq!(hgeip.pending().count(), 0);
+
+        // bit 0 is read-only zero and is ignored
+        let hgeip = Hgeip::from((1 << 1) | (1 << 5) | 1);
+        assert!(hgeip.guest(1));
+        assert!(hgeip.guest(5));
+        assert!(!hgeip.guest(2));
+        assert!(hgeip.pending().eq([1, 5]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hgeip_invalid_guest() {
+        Hgeip::from(usize::MAX).guest(usize::BITS as usize);
+    }
+}
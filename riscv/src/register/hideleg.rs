@@ -0,0 +1,70 @@
+//! hideleg register (Hypervisor extension)
+
+/// hideleg register
+///
+/// It delegates VS-level interrupts from HS-mode to VS-mode.
+#[derive(Clone, Copy, Debug)]
+pub struct Hideleg {
+    bits: usize,
+}
+
+impl From<usize> for Hideleg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hideleg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Virtual Supervisor Software Interrupt Delegate
+    #[inline]
+    pub fn vssoft(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Virtual Supervisor Timer Interrupt Delegate
+    #[inline]
+    pub fn vstimer(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Virtual Supervisor External Interrupt Delegate
+    #[inline]
+    pub fn vsext(&self) -> bool {
+        self.bits & (1 << 10) != 0
+    }
+}
+
+read_csr_as!(Hideleg, 0x603);
+write_csr!(0x603);
+set!(0x603);
+clear!(0x603);
+
+set_clear_csr!(
+    /// Virtual Supervisor Software Interrupt Delegate
+    , set_vssoft, clear_vssoft, 1 << 2);
+set_clear_csr!(
+    /// Virtual Supervisor Timer Interrupt Delegate
+    , set_vstimer, clear_vstimer, 1 << 6);
+set_clear_csr!(
+    /// Virtual Supervisor External Interrupt Delegate
+    , set_vsext, clear_vsext, 1 << 10);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hideleg() {
+        let hideleg = Hideleg::from((1 << 2) | (1 << 10));
+        assert!(hideleg.vssoft());
+        assert!(!hideleg.vstimer());
+        assert!(hideleg.vsext());
+    }
+}
@@ -0,0 +1,199 @@
+//! hstatus register (Hypervisor extension)
+
+pub use super::misa::XLEN;
+
+/// Hypervisor Status Register
+#[derive(Clone, Copy, Debug)]
+pub struct Hstatus {
+    bits: usize,
+}
+
+impl From<usize> for Hstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// VS-mode implicit memory accesses are big endian
+    #[inline]
+    pub fn vsbe(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Guest Virtual Address (`stval` holds a guest virtual address)
+    #[inline]
+    pub fn gva(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Supervisor Previous Virtualization mode
+    #[inline]
+    pub fn spv(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+
+    /// Supervisor Previous Virtual Privilege (`true` for VS-mode, `false` for VU-mode)
+    #[inline]
+    pub fn spvp(&self) -> bool {
+        self.bits & (1 << 8) != 0
+    }
+
+    /// Hypervisor in U-mode (U-mode may execute hypervisor load/store instructions)
+    #[inline]
+    pub fn hu(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+
+    /// Virtual Guest External Interrupt number (bits 12-17)
+    ///
+    /// It selects the guest external interrupt source for VS-level external interrupts.
+    /// Zero means that no guest external interrupt source is selected.
+    #[inline]
+    pub fn vgein(&self) -> usize {
+        (self.bits >> 12) & 0x3F
+    }
+
+    /// Virtual Trap Virtual Memory
+    #[inline]
+    pub fn vtvm(&self) -> bool {
+        self.bits & (1 << 20) != 0
+    }
+
+    /// Virtual Timeout Wait
+    #[inline]
+    pub fn vtw(&self) -> bool {
+        self.bits & (1 << 21) != 0
+    }
+
+    /// Virtual Trap SRET
+    #[inline]
+    pub fn vtsr(&self) -> bool {
+        self.bits & (1 << 22) != 0
+    }
+
+    /// Effective xlen in VS-mode (i.e., `VSXLEN`).
+    ///
+    /// In RISCV-32, VSXL does not exist, and `VSXLEN` is always [`XLEN::XLEN32`].
+    #[inline]
+    pub fn vsxl(&self) -> XLEN {
+        match () {
+            #[cfg(riscv32)]
+            () => XLEN::XLEN32,
+            #[cfg(not(riscv32))]
+            () => XLEN::from((self.bits >> 32) as u8 & 0x3),
+        }
+    }
+}
+
+read_csr_as!(Hstatus, 0x600);
+write_csr!(0x600);
+set!(0x600);
+clear!(0x600);
+
+set_clear_csr!(
+    /// VS-mode implicit memory accesses are big endian
+    , set_vsbe, clear_vsbe, 1 << 5);
+set_clear_csr!(
+    /// Guest Virtual Address
+    , set_gva, clear_gva, 1 << 6);
+set_clear_csr!(
+    /// Supervisor Previous Virtualization mode
+    , set_spv, clear_spv, 1 << 7);
+set_clear_csr!(
+    /// Supervisor Previous Virtual Privilege
+    , set_spvp, clear_spvp, 1 << 8);
+set_clear_csr!(
+    /// Hypervisor in U-mode
+    , set_hu, clear_hu, 1 << 9);
+set_clear_csr!(
+    /// Virtual Trap Virtual Memory
+    , set_vtvm, clear_vtvm, 1 << 20);
+set_clear_csr!(
+    /// Virtual Timeout Wait
+    , set_vtw, clear_vtw, 1 << 21);
+set_clear_csr!(
+    /// Virtual Trap SRET
+    , set_vtsr, clear_vtsr, 1 << 22);
+
+/// Virtual Guest External Interrupt number
+///
+/// Selects the guest interrupt file `guest` (e.g., of an IMSIC) for VS-level external interrupts.
+/// `GEILEN` is the number of guest external interrupt sources of the platform.
+/// Valid values of `guest` are `0` (no guest external interrupt source) to `GEILEN`.
+///
+/// It returns `Err(guest)` without modifying the register if `guest` is out of range.
+///
+/// # Note
+///
+/// `GEILEN` is at most 63 in RV64 and 31 in RV32.
+/// The bits of `hgeie` and `hgeip` corresponding to guests above `GEILEN` are read-only zero.
+#[inline]
+pub unsafe fn set_vgein<const GEILEN: usize>(guest: usize) -> Result<(), usize> {
+    let value = with_vgein::<GEILEN>(_read(), guest)?;
+    _write(value);
+    Ok(())
+}
+
+/// Returns `bits` with the VGEIN field replaced by `guest`, or `Err(guest)` if it is out of range.
+#[inline]
+const fn with_vgein<const GEILEN: usize>(bits: usize, guest: usize) -> Result<usize, usize> {
+    if guest > GEILEN || guest > 0x3F {
+        return Err(guest);
+    }
+    Ok((bits & !(0x3F << 12)) | (guest << 12))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hstatus() {
+        let hstatus = Hstatus::from((1 << 7) | (1 << 8) | (5 << 12) | (1 << 21));
+        assert!(!hstatus.vsbe());
+        assert!(!hstatus.gva());
+        assert!(hstatus.spv());
+        assert!(hstatus.spvp());
+        assert!(!hstatus.hu());
+        assert_eq!(hstatus.vgein(), 5);
+        assert!(!hstatus.vtvm());
+        assert!(hstatus.vtw());
+        assert!(!hstatus.vtsr());
+
+        assert_eq!(Hstatus::from(usize::MAX).vgein(), 0x3F);
+    }
+
+    #[test]
+    fn test_with_vgein() {
+        let bits = (1 << 7) | (1 << 21);
+
+        for guest in [0, 1, 7] {
+            let hstatus = Hstatus::from(with_vgein::<7>(bits, guest).unwrap());
+            assert_eq!(hstatus.vgein(), guest);
+            // the rest of the register is preserved
+            assert_eq!(hstatus.bits() & !(0x3F << 12), bits);
+        }
+        let hstatus = Hstatus::from(with_vgein::<7>(usize::MAX, 0).unwrap());
+        assert_eq!(hstatus.vgein(), 0);
+        assert_eq!(hstatus.bits(), usize::MAX & !(0x3F << 12));
+
+        // out-of-range guest indices
+        assert_eq!(with_vgein::<7>(bits, 8), Err(8));
+        assert_eq!(with_vgein::<0>(bits, 1), Err(1));
+        assert_eq!(
+            with_vgein::<63>(bits, 63).map(|b| Hstatus::from(b).vgein()),
+            Ok(63)
+        );
+        assert_eq!(with_vgein::<63>(bits, 64), Err(64));
+        // a misconfigured GEILEN cannot overflow the 6-bit field
+        assert_eq!(with_vgein::<100>(bits, 64), Err(64));
+    }
+}
@@ -0,0 +1,68 @@
+//! hvien register (AIA extension)
+
+/// hvien register
+///
+/// It selects which interrupts are virtual for VS-mode.
+#[derive(Clone, Copy, Debug)]
+pub struct Hvien {
+    bits: usize,
+}
+
+impl From<usize> for Hvien {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hvien {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Local Interrupt Enable (bits 13-XLEN)
+    #[inline]
+    pub fn local(&self, index: usize) -> bool {
+        assert!((13..usize::BITS as usize).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+}
+
+read_csr_as!(Hvien, 0x608);
+write_csr!(0x608);
+set!(0x608);
+clear!(0x608);
+
+/// Local Interrupt Enable (bits 13-XLEN)
+#[inline]
+pub unsafe fn set_local(index: usize) {
+    assert!((13..usize::BITS as usize).contains(&index));
+    _set(1 << index);
+}
+
+/// Local Interrupt Enable (bits 13-XLEN)
+#[inline]
+pub unsafe fn clear_local(index: usize) {
+    assert!((13..usize::BITS as usize).contains(&index));
+    _clear(1 << index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hvien() {
+        let hvien = Hvien::from(1 << 31);
+        assert!(hvien.local(31));
+        assert!(!hvien.local(13));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hvien_invalid_local() {
+        Hvien::from(0).local(12);
+    }
+}
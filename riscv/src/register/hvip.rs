@@ -0,0 +1,98 @@
+//! hvip register (AIA extension)
+
+/// hvip register
+///
+/// It allows a hypervisor to inject interrupts into VS-mode.
+#[derive(Clone, Copy, Debug)]
+pub struct Hvip {
+    bits: usize,
+}
+
+impl From<usize> for Hvip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hvip {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Virtual Supervisor Software Interrupt Pending
+    #[inline]
+    pub fn vssoft(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Virtual Supervisor Timer Interrupt Pending
+    #[inline]
+    pub fn vstimer(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Virtual Supervisor External Interrupt Pending
+    #[inline]
+    pub fn vsext(&self) -> bool {
+        self.bits & (1 << 10) != 0
+    }
+
+    /// Local Interrupt Pending (bits 13-XLEN)
+    #[inline]
+    pub fn local(&self, index: usize) -> bool {
+        assert!((13..usize::BITS as usize).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+}
+
+read_csr_as!(Hvip, 0x645);
+write_csr!(0x645);
+set!(0x645);
+clear!(0x645);
+
+set_clear_csr!(
+    /// Virtual Supervisor Software Interrupt Pending
+    , set_vssoft, clear_vssoft, 1 << 2);
+set_clear_csr!(
+    /// Virtual Supervisor Timer Interrupt Pending
+    , set_vstimer, clear_vstimer, 1 << 6);
+set_clear_csr!(
+    /// Virtual Supervisor External Interrupt Pending
+    , set_vsext, clear_vsext, 1 << 10);
+
+/// Local Interrupt Pending (bits 13-XLEN)
+#[inline]
+pub unsafe fn set_local(index: usize) {
+    assert!((13..usize::BITS as usize).contains(&index));
+    _set(1 << index);
+}
+
+/// Local Interrupt Pending (bits 13-XLEN)
+#[inline]
+pub unsafe fn clear_local(index: usize) {
+    assert!((13..usize::BITS as usize).contains(&index));
+    _clear(1 << index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hvip() {
+        let hvip = Hvip::from((1 << 2) | (1 << 10));
+        assert!(hvip.vssoft());
+        assert!(!hvip.vstimer());
+        assert!(hvip.vsext());
+        assert!(!hvip.local(13));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hvip_invalid_local() {
+        Hvip::from(0).local(12);
+    }
+}
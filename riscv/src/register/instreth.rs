@@ -3,5 +3,29 @@
 //! Shadow of minstreth register (rv32)
 //! must have `scounteren::ir` or `mcounteren::ir` bit enabled depending on whether
 //! S-mode is implemented or not
+//!
+//! Only available on RV32. On other targets, [`read`] panics.
+//! Use [`crate::register::instret::read64`] to read the full 64-bit counter on any target.
+
+read_csr_as_usize_rv32!(0xC82);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(riscv32)]
+    #[test]
+    fn test_read() {
+        // the counter is monotonic, so its upper half never goes backwards
+        let hi = read();
+        let full = crate::register::instret::read64();
+        assert!((full >> 32) as usize >= hi);
+    }
 
-read_csr_as_usize!(0xC82);
+    #[cfg(not(riscv32))]
+    #[test]
+    #[should_panic]
+    fn test_read_unsupported() {
+        read();
+    }
+}
@@ -0,0 +1,273 @@
+//! Interrupt flags shared by the interrupt enable and pending registers
+
+use super::{mcause, scause};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+/// Set of standard interrupt bits, as laid out in `mie`/`mip` and `sie`/`sip`.
+///
+/// Flags can be combined with `|` to enable or disable several interrupt sources
+/// with a single CSR write (e.g., `mie::enable(InterruptFlags::MSOFT | InterruptFlags::MTIMER)`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct InterruptFlags {
+    bits: usize,
+}
+
+impl InterruptFlags {
+    /// Supervisor Software Interrupt
+    pub const SSOFT: Self = Self { bits: 1 << 1 };
+    /// Machine Software Interrupt
+    pub const MSOFT: Self = Self { bits: 1 << 3 };
+    /// Supervisor Timer Interrupt
+    pub const STIMER: Self = Self { bits: 1 << 5 };
+    /// Machine Timer Interrupt
+    pub const MTIMER: Self = Self { bits: 1 << 7 };
+    /// Supervisor External Interrupt
+    pub const SEXT: Self = Self { bits: 1 << 9 };
+    /// Machine External Interrupt
+    pub const MEXT: Self = Self { bits: 1 << 11 };
+
+    /// Returns an empty set of flags
+    #[inline]
+    pub const fn empty() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Returns the set of all the standard interrupt flags
+    #[inline]
+    pub const fn all() -> Self {
+        Self {
+            bits: Self::SSOFT.bits
+                | Self::MSOFT.bits
+                | Self::STIMER.bits
+                | Self::MTIMER.bits
+                | Self::SEXT.bits
+                | Self::MEXT.bits,
+        }
+    }
+
+    /// Returns the set of supervisor-level interrupt flags
+    #[inline]
+    pub const fn supervisor() -> Self {
+        Self {
+            bits: Self::SSOFT.bits | Self::STIMER.bits | Self::SEXT.bits,
+        }
+    }
+
+    /// Creates a set of flags from raw bits, discarding non-standard interrupt bits
+    #[inline]
+    pub const fn from_bits_truncate(bits: usize) -> Self {
+        Self {
+            bits: bits & Self::all().bits,
+        }
+    }
+
+    /// Returns the contents of the set as raw bits
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns `true` if no flag is set
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns `true` if all the flags in `other` are also in `self`
+    #[inline]
+    pub const fn contains(&self, other: Self) -> bool {
+        self.bits & other.bits == other.bits
+    }
+}
+
+impl BitOr for InterruptFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            bits: self.bits | rhs.bits,
+        }
+    }
+}
+
+impl BitOrAssign for InterruptFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.bits |= rhs.bits;
+    }
+}
+
+impl BitAnd for InterruptFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self {
+            bits: self.bits & rhs.bits,
+        }
+    }
+}
+
+impl BitAndAssign for InterruptFlags {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.bits &= rhs.bits;
+    }
+}
+
+impl Not for InterruptFlags {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self::from_bits_truncate(!self.bits)
+    }
+}
+
+/// Returns the flag of a standard interrupt.
+///
+/// The bit of each standard interrupt in `mie`/`mip` matches its exception code in `mcause`
+/// (e.g., `Interrupt::MachineTimer` maps to [`InterruptFlags::MTIMER`]).
+/// It returns `Err(Interrupt::Unknown)` for non-standard interrupts.
+impl TryFrom<mcause::Interrupt> for InterruptFlags {
+    type Error = mcause::Interrupt;
+
+    #[inline]
+    fn try_from(value: mcause::Interrupt) -> Result<Self, Self::Error> {
+        usize::try_from(value).map(|code| Self { bits: 1 << code })
+    }
+}
+
+/// Returns the flag of a standard supervisor interrupt.
+///
+/// The bit of each standard interrupt in `sie`/`sip` matches its exception code in `scause`
+/// (e.g., `Interrupt::SupervisorTimer` maps to [`InterruptFlags::STIMER`]).
+/// It returns `Err(Interrupt::Unknown)` for non-standard interrupts.
+impl TryFrom<scause::Interrupt> for InterruptFlags {
+    type Error = scause::Interrupt;
+
+    #[inline]
+    fn try_from(value: scause::Interrupt) -> Result<Self, Self::Error> {
+        usize::try_from(value).map(|code| Self { bits: 1 << code })
+    }
+}
+
+/// Uniform access to the interrupt enable register of a privilege mode (i.e., `mie` or `sie`).
+///
+/// It allows writing privilege-generic code once, selecting the privilege mode with a type parameter.
+pub trait InterruptEnableCsr: Copy {
+    /// Software interrupt of the privilege mode
+    const SOFT: InterruptFlags;
+    /// Timer interrupt of the privilege mode
+    const TIMER: InterruptFlags;
+    /// External interrupt of the privilege mode
+    const EXT: InterruptFlags;
+
+    /// Reads the CSR
+    fn read() -> Self;
+
+    /// Returns the enabled standard interrupts as a set of flags
+    fn flags(&self) -> InterruptFlags;
+
+    /// Software Interrupt Enable of the privilege mode
+    #[inline]
+    fn soft(&self) -> bool {
+        self.flags().contains(Self::SOFT)
+    }
+
+    /// Timer Interrupt Enable of the privilege mode
+    #[inline]
+    fn timer(&self) -> bool {
+        self.flags().contains(Self::TIMER)
+    }
+
+    /// External Interrupt Enable of the privilege mode
+    #[inline]
+    fn ext(&self) -> bool {
+        self.flags().contains(Self::EXT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::register::{mie::Mie, sie::Sie};
+
+    #[test]
+    fn test_interrupt_flags() {
+        let flags = InterruptFlags::MSOFT | InterruptFlags::MTIMER;
+        assert_eq!(flags.bits(), (1 << 3) | (1 << 7));
+        assert!(flags.contains(InterruptFlags::MSOFT));
+        assert!(flags.contains(InterruptFlags::MTIMER));
+        assert!(!flags.contains(InterruptFlags::MEXT));
+        assert!(!flags.contains(InterruptFlags::all()));
+
+        assert_eq!(InterruptFlags::all().bits(), 0b1010_1010_1010);
+        assert_eq!(InterruptFlags::supervisor().bits(), 0b0010_0010_0010);
+        assert_eq!(!InterruptFlags::all(), InterruptFlags::empty());
+        assert_eq!(
+            !flags & InterruptFlags::all(),
+            InterruptFlags::SSOFT
+                | InterruptFlags::STIMER
+                | InterruptFlags::SEXT
+                | InterruptFlags::MEXT
+        );
+        assert_eq!(
+            InterruptFlags::from_bits_truncate(usize::MAX),
+            InterruptFlags::all()
+        );
+        assert!(InterruptFlags::default().is_empty());
+    }
+
+    #[test]
+    fn test_interrupt_conversions() {
+        use mcause::Interrupt as M;
+        use scause::Interrupt as S;
+
+        for (interrupt, flag) in [
+            (M::SupervisorSoft, InterruptFlags::SSOFT),
+            (M::MachineSoft, InterruptFlags::MSOFT),
+            (M::SupervisorTimer, InterruptFlags::STIMER),
+            (M::MachineTimer, InterruptFlags::MTIMER),
+            (M::SupervisorExternal, InterruptFlags::SEXT),
+            (M::MachineExternal, InterruptFlags::MEXT),
+        ] {
+            assert_eq!(InterruptFlags::try_from(interrupt), Ok(flag));
+            // the bit position of the flag is the exception code of the interrupt
+            let code = flag.bits().trailing_zeros() as usize;
+            assert_eq!(usize::try_from(interrupt), Ok(code));
+            assert_eq!(M::from(code), interrupt);
+        }
+        assert_eq!(InterruptFlags::try_from(M::Unknown), Err(M::Unknown));
+
+        for (interrupt, flag) in [
+            (S::SupervisorSoft, InterruptFlags::SSOFT),
+            (S::SupervisorTimer, InterruptFlags::STIMER),
+            (S::SupervisorExternal, InterruptFlags::SEXT),
+        ] {
+            assert_eq!(InterruptFlags::try_from(interrupt), Ok(flag));
+            let code = flag.bits().trailing_zeros() as usize;
+            assert_eq!(usize::try_from(interrupt), Ok(code));
+            assert_eq!(S::from(code), interrupt);
+            assert!(InterruptFlags::supervisor().contains(flag));
+        }
+        assert_eq!(InterruptFlags::try_from(S::Unknown), Err(S::Unknown));
+    }
+
+    /// Privilege-generic function, written once for both `mie` and `sie`.
+    fn enabled_sources<T: InterruptEnableCsr>(csr: T) -> (bool, bool, bool) {
+        (csr.soft(), csr.timer(), csr.ext())
+    }
+
+    #[test]
+    fn test_interrupt_enable_csr() {
+        let bits = (1 << 3) | (1 << 9) | (1 << 11); // MSOFT, SEXT, MEXT
+        assert_eq!(enabled_sources(Mie::from(bits)), (true, false, true));
+        assert_eq!(enabled_sources(Sie::from(bits)), (false, false, true));
+
+        let bits = (1 << 1) | (1 << 5); // SSOFT, STIMER
+        assert_eq!(enabled_sources(Mie::from(bits)), (false, false, false));
+        assert_eq!(enabled_sources(Sie::from(bits)), (true, true, false));
+    }
+}
@@ -0,0 +1,74 @@
+//! jvt register (Zcmt extension)
+
+/// jvt register
+#[derive(Clone, Copy, Debug)]
+pub struct Jvt {
+    bits: usize,
+}
+
+impl From<usize> for Jvt {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+/// Table jump mode
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Jump table mode: `cm.jt` and `cm.jalt` index the table at `base`
+    JumpTable = 0,
+}
+
+impl Jvt {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the jump vector table base address (64-byte aligned)
+    #[inline]
+    pub fn base(&self) -> usize {
+        self.bits & !0x3F
+    }
+
+    /// Returns the table jump mode
+    #[inline]
+    pub fn mode(&self) -> Option<Mode> {
+        match self.bits & 0x3F {
+            0 => Some(Mode::JumpTable),
+            _ => None,
+        }
+    }
+}
+
+read_csr_as!(Jvt, 0x017);
+write_csr!(0x017);
+
+/// Writes the CSR
+///
+/// The lowest 6 bits of `base` are discarded, as the jump vector table must be 64-byte aligned.
+#[inline]
+pub unsafe fn write(base: usize, mode: Mode) {
+    _write((base & !0x3F) | mode as usize);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jvt() {
+        let jvt = Jvt::from(0x8000_1040);
+        assert_eq!(jvt.base(), 0x8000_1040);
+        assert_eq!(jvt.mode(), Some(Mode::JumpTable));
+
+        let jvt = Jvt::from(0x8000_107F);
+        assert_eq!(jvt.base(), 0x8000_1040);
+        assert_eq!(jvt.mode(), None);
+
+        let jvt = Jvt::from(usize::MAX);
+        assert_eq!(jvt.base(), usize::MAX - 0x3F);
+    }
+}
@@ -250,13 +250,7 @@ macro_rules! read_composite_csr {
         pub fn read64() -> u64 {
             match () {
                 #[cfg(riscv32)]
-                () => loop {
-                    let hi = $hi;
-                    let lo = $lo;
-                    if hi == $hi {
-                        return ((hi as u64) << 32) | lo as u64;
-                    }
-                },
+                () => crate::register::macros::read_csr64(|| $lo, || $hi),
 
                 #[cfg(not(riscv32))]
                 () => $lo as u64,
@@ -265,6 +259,26 @@ macro_rules! read_composite_csr {
     };
 }
 
+macro_rules! write_composite_csr {
+    ($hi:path, $lo:path) => {
+        /// Writes the CSR as a 64-bit value
+        ///
+        /// # Safety
+        ///
+        /// Any code measuring elapsed events with this counter is affected.
+        #[inline]
+        pub unsafe fn write64(value: u64) {
+            match () {
+                #[cfg(riscv32)]
+                () => crate::register::macros::write_csr64(value, |lo| $lo(lo), |hi| $hi(hi)),
+
+                #[cfg(not(riscv32))]
+                () => $lo(value as usize),
+            }
+        }
+    };
+}
+
 macro_rules! set_pmp {
     () => {
         /// Set the pmp configuration corresponding to the index
@@ -285,6 +299,47 @@ macro_rules! set_pmp {
     };
 }
 
+macro_rules! set_pmp_mml {
+    () => {
+        /// Set the pmp configuration corresponding to the index using Machine Mode Lockdown rules
+        ///
+        /// The lock bit is determined by `permission`. Only meaningful when `mseccfg.MML` is set.
+        #[inline]
+        pub unsafe fn set_pmp_mml(index: usize, range: Range, permission: MmlPermission) {
+            #[cfg(riscv32)]
+            assert!(index < 4);
+
+            #[cfg(riscv64)]
+            assert!(index < 8);
+
+            let mut value = _read();
+            value &= !(0xFF << (8 * index)); // clear previous value
+            value |= (permission.encode(range) as usize) << (8 * index);
+            _write(value);
+        }
+    };
+}
+
+macro_rules! lock_pmp {
+    () => {
+        /// Lock the pmp configuration corresponding to the index
+        ///
+        /// A locked entry cannot be modified until reset, unless `mseccfg.RLB` is set.
+        #[inline]
+        pub unsafe fn lock_pmp(index: usize) {
+            #[cfg(riscv32)]
+            assert!(index < 4);
+
+            #[cfg(riscv64)]
+            assert!(index < 8);
+
+            let mut value = _read();
+            value |= 1 << (7 + 8 * index);
+            _write(value);
+        }
+    };
+}
+
 macro_rules! clear_pmp {
     () => {
         /// Clear the pmp configuration corresponding to the index
@@ -302,3 +357,102 @@ macro_rules! clear_pmp {
         }
     };
 }
+
+/// Reads a 64-bit value split in two 32-bit CSRs without tearing.
+///
+/// If the upper half changes while reading the lower half (i.e., the lower half rolled over),
+/// both halves are read again.
+#[cfg(any(riscv32, test))]
+#[inline]
+pub(crate) fn read_csr64<L: FnMut() -> usize, H: FnMut() -> usize>(
+    mut read_lo: L,
+    mut read_hi: H,
+) -> u64 {
+    loop {
+        let hi = read_hi();
+        let lo = read_lo();
+        if hi == read_hi() {
+            return ((hi as u64) << 32) | lo as u64;
+        }
+    }
+}
+
+/// Writes a 64-bit value split in two 32-bit CSRs without spurious carries.
+///
+/// The lower half is cleared before writing the upper half, so a counter that increments
+/// in the meantime cannot carry into the new upper half.
+#[cfg(any(riscv32, test))]
+#[inline]
+pub(crate) fn write_csr64<L: FnMut(usize), H: FnMut(usize)>(
+    value: u64,
+    mut write_lo: L,
+    mut write_hi: H,
+) {
+    write_lo(0);
+    write_hi((value >> 32) as usize);
+    write_lo(value as u32 as usize);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    /// Emulates a 64-bit counter that increments after every access to any of its halves.
+    fn tick(counter: &Cell<u64>) -> u64 {
+        let value = counter.get();
+        counter.set(value + 1);
+        value
+    }
+
+    #[test]
+    fn test_read_csr64() {
+        let c = Cell::new(0x1234_5678_0000_0000);
+        let value = read_csr64(|| tick(&c) as u32 as usize, || (tick(&c) >> 32) as usize);
+        assert_eq!(value, 0x1234_5678_0000_0001);
+    }
+
+    #[test]
+    fn test_read_csr64_rollover() {
+        // the lower half rolls over between the two reads of the upper half
+        let c = Cell::new(0x1_FFFF_FFFF);
+        let (lo_reads, hi_reads) = (Cell::new(0), Cell::new(0));
+        let value = read_csr64(
+            || {
+                lo_reads.set(lo_reads.get() + 1);
+                tick(&c) as u32 as usize
+            },
+            || {
+                hi_reads.set(hi_reads.get() + 1);
+                (tick(&c) >> 32) as usize
+            },
+        );
+        // a torn read would return 0x1_0000_0000
+        assert_eq!(value, 0x2_0000_0003);
+        assert_eq!(lo_reads.get(), 2);
+        assert_eq!(hi_reads.get(), 4);
+    }
+
+    #[test]
+    fn test_write_csr64() {
+        // the lower half is about to roll over when the new value is written
+        let c = Cell::new(0xFFFF_FFFF);
+        let value = 0x1234_5678_9ABC_DEF0;
+        write_csr64(
+            value,
+            |lo| {
+                c.set((c.get() & !0xFFFF_FFFF) | lo as u64);
+                tick(&c);
+            },
+            |hi| {
+                c.set((c.get() & 0xFFFF_FFFF) | (hi as u64) << 32);
+                tick(&c);
+            },
+        );
+        // writing the upper half first would have returned 0x1234_5679_xxxx_xxxx
+        assert_eq!(c.get(), value + 1);
+
+        let read = read_csr64(|| tick(&c) as u32 as usize, || (tick(&c) >> 32) as usize);
+        assert_eq!(read, value + 2);
+    }
+}
@@ -0,0 +1,63 @@
+//! mconfigptr register
+//!
+//! `mconfigptr` holds the physical address of a configuration data structure that describes the platform.
+//! The format of the structure is platform-defined (the RISC-V specification recommends, but does not mandate,
+//! an ASN.1 encoding), so this crate does not parse it. A value of zero means that the structure does not exist.
+//!
+//! # Walking the configuration structure
+//!
+//! Firmware that knows the format of its platform can cast the pointer to the expected header and
+//! follow it. The structure must be accessed before enabling address translation (it is a physical
+//! address), and its size must be taken from the structure itself:
+//!
+//! ```no_run
+//! use riscv::register::mconfigptr;
+//!
+//! /// Hypothetical platform-defined header.
+//! #[repr(C)]
+//! struct ConfigHeader {
+//!     magic: u32,
+//!     len: u32,
+//! }
+//!
+//! if let Some(ptr) = mconfigptr::config_ptr() {
+//!     // SAFETY: the platform guarantees that `mconfigptr` points to a `ConfigHeader`
+//!     let header = unsafe { &*(ptr as *const ConfigHeader) };
+//!     // SAFETY: the platform guarantees that `len` bytes are readable after the header
+//!     let _blob = unsafe {
+//!         core::slice::from_raw_parts(
+//!             (ptr as *const u8).add(core::mem::size_of::<ConfigHeader>()),
+//!             header.len as usize,
+//!         )
+//!     };
+//! }
+//! ```
+
+read_csr_as_usize!(0xF15);
+
+/// Returns a pointer to the platform configuration data structure,
+/// or `None` if `mconfigptr` is zero (i.e., there is no such structure).
+#[inline]
+pub fn config_ptr() -> Option<*const ()> {
+    config_ptr_from(read())
+}
+
+/// Maps a raw `mconfigptr` value to a pointer.
+#[inline]
+fn config_ptr_from(bits: usize) -> Option<*const ()> {
+    match bits {
+        0 => None,
+        addr => Some(addr as *const ()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_ptr() {
+        assert_eq!(config_ptr_from(0), None);
+        assert_eq!(config_ptr_from(0x8000_1000), Some(0x8000_1000 as *const ()));
+    }
+}
@@ -0,0 +1,74 @@
+//! mcontext register
+//!
+//! Machine context register of the debug trigger module (Sdtrig).
+//! Triggers can be configured to only match when the `hcontext` field equals a given value.
+
+/// Mask of the `hcontext` field.
+///
+/// The specification recommends implementing 6 bits on RV32 and 13 bits on RV64.
+/// Implementations may hardwire any number of the upper bits to zero.
+#[cfg(target_pointer_width = "32")]
+pub const HCONTEXT_MASK: usize = 0x3F;
+
+/// Mask of the `hcontext` field.
+///
+/// The specification recommends implementing 6 bits on RV32 and 13 bits on RV64.
+/// Implementations may hardwire any number of the upper bits to zero.
+#[cfg(target_pointer_width = "64")]
+pub const HCONTEXT_MASK: usize = 0x1FFF;
+
+/// mcontext register
+#[derive(Clone, Copy, Debug)]
+pub struct Mcontext {
+    bits: usize,
+}
+
+impl From<usize> for Mcontext {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mcontext {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Hypervisor context (`hcontext` field)
+    #[inline]
+    pub fn hcontext(&self) -> usize {
+        self.bits & HCONTEXT_MASK
+    }
+}
+
+read_csr_as!(Mcontext, 0x7A8);
+write_csr!(0x7A8);
+
+/// Writes the `hcontext` field of the CSR
+#[inline]
+pub fn write(hcontext: usize) {
+    unsafe { _write(hcontext & HCONTEXT_MASK) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mcontext() {
+        for bits in [0, 1, 0x2A, HCONTEXT_MASK] {
+            let mcontext = Mcontext::from(bits);
+            assert_eq!(mcontext.bits(), bits);
+            assert_eq!(mcontext.hcontext(), bits);
+        }
+
+        // bits above the hcontext field are ignored
+        let mcontext = Mcontext::from(usize::MAX);
+        assert_eq!(mcontext.bits(), usize::MAX);
+        assert_eq!(mcontext.hcontext(), HCONTEXT_MASK);
+        assert_eq!(Mcontext::from(HCONTEXT_MASK + 1).hcontext(), 0);
+    }
+}
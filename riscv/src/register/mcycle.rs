@@ -2,3 +2,15 @@
 
 read_csr_as_usize!(0xB00);
 read_composite_csr!(super::mcycleh::read(), read());
+write_csr!(0xB00);
+write_composite_csr!(super::mcycleh::write, write);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// Any code measuring elapsed cycles with this counter is affected.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
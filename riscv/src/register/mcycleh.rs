@@ -1,3 +1,14 @@
 //! mcycleh register
 
 read_csr_as_usize_rv32!(0xB80);
+write_csr_rv32!(0xB80);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// Any code measuring elapsed cycles with this counter is affected.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
@@ -0,0 +1,231 @@
+//! menvcfg register
+
+pub use super::henvcfg::{AdUpdate, PointerMaskingMode, CBIE};
+
+/// menvcfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Menvcfg {
+    bits: usize,
+}
+
+impl From<usize> for Menvcfg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Menvcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Fence of I/O implies Memory
+    #[inline]
+    pub fn fiom(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// Landing Pad Enable (Zicfilp extension)
+    #[inline]
+    pub fn lpe(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Cache Block Invalidate instruction Enable (Zicbom extension)
+    #[inline]
+    pub fn cbie(&self) -> Option<CBIE> {
+        match (self.bits >> 4) & 0x3 {
+            0b00 => Some(CBIE::IllegalInstruction),
+            0b01 => Some(CBIE::Flush),
+            0b11 => Some(CBIE::Invalidate),
+            _ => None,
+        }
+    }
+
+    /// Cache Block Clean and Flush instruction Enable (Zicbom extension)
+    #[inline]
+    pub fn cbcfe(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Cache Block Zero instruction Enable (Zicboz extension)
+    #[inline]
+    pub fn cbze(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+
+    /// Pointer Masking Mode for the next-lower privilege mode (Smnpm extension)
+    ///
+    /// Pointer masking is only supported in RISCV-64.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn pmm(&self) -> Option<PointerMaskingMode> {
+        match (self.bits >> 32) & 0x3 {
+            0b00 => Some(PointerMaskingMode::Disabled),
+            0b10 => Some(PointerMaskingMode::Bits7),
+            0b11 => Some(PointerMaskingMode::Bits16),
+            _ => None,
+        }
+    }
+
+    /// Counter Delegation Enable (Smcdeleg extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::menvcfgh`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn cde(&self) -> bool {
+        self.bits & (1 << 60) != 0
+    }
+
+    /// Hardware A/D bit updating Enable (Svadu extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::menvcfgh`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn adue(&self) -> bool {
+        self.bits & (1 << 61) != 0
+    }
+
+    /// Returns whether the A/D bits of S-mode and G-stage PTEs are updated by the MMU or by software.
+    ///
+    /// If the Svadu extension is not implemented, ADUE is read-only zero and
+    /// this returns [`AdUpdate::Software`]: page table code must set the A/D bits itself.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn ad_update(&self) -> AdUpdate {
+        AdUpdate::from_adue(self.adue())
+    }
+
+    /// Page-Based Memory Types Enable (Svpbmt extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::menvcfgh`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn pbmte(&self) -> bool {
+        self.bits & (1 << 62) != 0
+    }
+
+    /// S-mode timer compare Enable (Sstc extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::menvcfgh`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn stce(&self) -> bool {
+        self.bits & (1 << 63) != 0
+    }
+}
+
+read_csr_as!(Menvcfg, 0x30A);
+write_csr!(0x30A);
+set!(0x30A);
+clear!(0x30A);
+
+set_clear_csr!(
+    /// Fence of I/O implies Memory
+    , set_fiom, clear_fiom, 1 << 0);
+set_clear_csr!(
+    /// Landing Pad Enable
+    , set_lpe, clear_lpe, 1 << 2);
+set_clear_csr!(
+    /// Cache Block Clean and Flush instruction Enable
+    , set_cbcfe, clear_cbcfe, 1 << 6);
+set_clear_csr!(
+    /// Cache Block Zero instruction Enable
+    , set_cbze, clear_cbze, 1 << 7);
+set_clear_csr!(
+    /// Counter Delegation Enable
+    #[cfg(target_pointer_width = "64")]
+    , set_cde, clear_cde, 1 << 60);
+set_clear_csr!(
+    /// Hardware A/D bit updating Enable
+    #[cfg(target_pointer_width = "64")]
+    , set_adue, clear_adue, 1 << 61);
+set_clear_csr!(
+    /// Page-Based Memory Types Enable
+    #[cfg(target_pointer_width = "64")]
+    , set_pbmte, clear_pbmte, 1 << 62);
+set_clear_csr!(
+    /// S-mode timer compare Enable
+    #[cfg(target_pointer_width = "64")]
+    , set_stce, clear_stce, 1 << 63);
+
+/// Cache Block Invalidate instruction Enable
+#[inline]
+pub unsafe fn set_cbie(cbie: CBIE) {
+    let mut value = _read();
+    value &= !(0x3 << 4); // clear previous value
+    value |= (cbie as usize) << 4;
+    _write(value);
+}
+
+/// Pointer Masking Mode
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn set_pmm(pmm: PointerMaskingMode) {
+    let mut value = _read();
+    value &= !(0x3 << 32); // clear previous value
+    value |= (pmm as usize) << 32;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_menvcfg() {
+        let menvcfg = Menvcfg::from(0);
+        assert!(!menvcfg.fiom());
+        assert_eq!(menvcfg.cbie(), Some(CBIE::IllegalInstruction));
+        assert!(!menvcfg.cbcfe());
+        assert!(!menvcfg.cbze());
+
+        let menvcfg = Menvcfg::from((1 << 0) | (0b11 << 4) | (1 << 7));
+        assert!(menvcfg.fiom());
+        assert_eq!(menvcfg.cbie(), Some(CBIE::Invalidate));
+        assert!(!menvcfg.cbcfe());
+        assert!(menvcfg.cbze());
+
+        assert_eq!(Menvcfg::from(0b10 << 4).cbie(), None);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let menvcfg = Menvcfg::from((1 << 63) | (1 << 60));
+            assert!(menvcfg.stce());
+            assert!(menvcfg.cde());
+            assert!(!menvcfg.pbmte());
+            assert!(!menvcfg.adue());
+            assert_eq!(menvcfg.ad_update(), AdUpdate::Software);
+
+            // toggling ADUE switches between Svade and Svadu
+            let mut bits = menvcfg.bits() | (1 << 61);
+            let menvcfg = Menvcfg::from(bits);
+            assert!(menvcfg.adue());
+            assert_eq!(menvcfg.ad_update(), AdUpdate::Hardware);
+            assert!(menvcfg.stce());
+            bits &= !(1 << 61);
+            assert!(!Menvcfg::from(bits).adue());
+            assert_eq!(Menvcfg::from(bits).ad_update(), AdUpdate::Software);
+
+            assert_eq!(menvcfg.pmm(), Some(PointerMaskingMode::Disabled));
+            assert_eq!(
+                Menvcfg::from(0b11 << 32).pmm(),
+                Some(PointerMaskingMode::Bits16)
+            );
+            assert_eq!(Menvcfg::from(0b01 << 32).pmm(), None);
+        }
+
+        // Zicfilp landing pads
+        let mut bits = 0b11 << 4;
+        assert!(!Menvcfg::from(bits).lpe());
+        bits |= 1 << 2;
+        let menvcfg = Menvcfg::from(bits);
+        assert!(menvcfg.lpe());
+        assert_eq!(menvcfg.cbie(), Some(CBIE::Invalidate));
+        bits &= !(1 << 2);
+        assert!(!Menvcfg::from(bits).lpe());
+    }
+}
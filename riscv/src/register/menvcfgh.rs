@@ -0,0 +1,75 @@
+//! menvcfgh register (RISCV-32 only)
+
+pub use super::henvcfg::AdUpdate;
+
+/// menvcfgh register
+#[derive(Clone, Copy, Debug)]
+pub struct Menvcfgh {
+    bits: usize,
+}
+
+impl From<usize> for Menvcfgh {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Menvcfgh {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Counter Delegation Enable (Smcdeleg extension)
+    #[inline]
+    pub fn cde(&self) -> bool {
+        self.bits & (1 << 28) != 0
+    }
+
+    /// Hardware A/D bit updating Enable (Svadu extension)
+    #[inline]
+    pub fn adue(&self) -> bool {
+        self.bits & (1 << 29) != 0
+    }
+
+    /// Returns whether the A/D bits of S-mode and G-stage PTEs are updated by the MMU or by software.
+    ///
+    /// If the Svadu extension is not implemented, ADUE is read-only zero and
+    /// this returns [`AdUpdate::Software`]: page table code must set the A/D bits itself.
+    #[inline]
+    pub fn ad_update(&self) -> AdUpdate {
+        AdUpdate::from_adue(self.adue())
+    }
+
+    /// Page-Based Memory Types Enable (Svpbmt extension)
+    #[inline]
+    pub fn pbmte(&self) -> bool {
+        self.bits & (1 << 30) != 0
+    }
+
+    /// S-mode timer compare Enable (Sstc extension)
+    #[inline]
+    pub fn stce(&self) -> bool {
+        self.bits & (1 << 31) != 0
+    }
+}
+
+read_csr_as_rv32!(Menvcfgh, 0x31A);
+write_csr_rv32!(0x31A);
+set_rv32!(0x31A);
+clear_rv32!(0x31A);
+
+set_clear_csr!(
+    /// Counter Delegation Enable
+    , set_cde, clear_cde, 1 << 28);
+set_clear_csr!(
+    /// Hardware A/D bit updating Enable
+    , set_adue, clear_adue, 1 << 29);
+set_clear_csr!(
+    /// Page-Based Memory Types Enable
+    , set_pbmte, clear_pbmte, 1 << 30);
+set_clear_csr!(
+    /// S-mode timer compare Enable
+    , set_stce, clear_stce, 1 << 31);
@@ -10,6 +10,18 @@ macro_rules! reg {
     };
 }
 
+macro_rules! regh {
+    (
+        $addr:expr, $csrh:ident
+    ) => {
+        /// Upper 32 bits of machine performance-monitoring event selector (RV32I only)
+        pub mod $csrh {
+            read_csr_as_usize_rv32!($addr);
+            write_csr_as_usize_rv32!($addr);
+        }
+    };
+}
+
 reg!(0x323, mhpmevent3);
 reg!(0x324, mhpmevent4);
 reg!(0x325, mhpmevent5);
@@ -39,3 +51,33 @@ reg!(0x33C, mhpmevent28);
 reg!(0x33D, mhpmevent29);
 reg!(0x33E, mhpmevent30);
 reg!(0x33F, mhpmevent31);
+
+regh!(0x723, mhpmevent3h);
+regh!(0x724, mhpmevent4h);
+regh!(0x725, mhpmevent5h);
+regh!(0x726, mhpmevent6h);
+regh!(0x727, mhpmevent7h);
+regh!(0x728, mhpmevent8h);
+regh!(0x729, mhpmevent9h);
+regh!(0x72A, mhpmevent10h);
+regh!(0x72B, mhpmevent11h);
+regh!(0x72C, mhpmevent12h);
+regh!(0x72D, mhpmevent13h);
+regh!(0x72E, mhpmevent14h);
+regh!(0x72F, mhpmevent15h);
+regh!(0x730, mhpmevent16h);
+regh!(0x731, mhpmevent17h);
+regh!(0x732, mhpmevent18h);
+regh!(0x733, mhpmevent19h);
+regh!(0x734, mhpmevent20h);
+regh!(0x735, mhpmevent21h);
+regh!(0x736, mhpmevent22h);
+regh!(0x737, mhpmevent23h);
+regh!(0x738, mhpmevent24h);
+regh!(0x739, mhpmevent25h);
+regh!(0x73A, mhpmevent26h);
+regh!(0x73B, mhpmevent27h);
+regh!(0x73C, mhpmevent28h);
+regh!(0x73D, mhpmevent29h);
+regh!(0x73E, mhpmevent30h);
+regh!(0x73F, mhpmevent31h);
@@ -1,11 +1,20 @@
 //! mie register
 
+pub use super::{InterruptEnableCsr, InterruptFlags};
+
 /// mie register
 #[derive(Clone, Copy, Debug)]
 pub struct Mie {
     bits: usize,
 }
 
+impl From<usize> for Mie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Mie {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -13,6 +22,12 @@ impl Mie {
         self.bits
     }
 
+    /// Returns the enabled standard interrupts as a set of flags
+    #[inline]
+    pub fn flags(&self) -> InterruptFlags {
+        InterruptFlags::from_bits_truncate(self.bits)
+    }
+
     /// Supervisor Software Interrupt Enable
     #[inline]
     pub fn ssoft(&self) -> bool {
@@ -48,12 +63,53 @@ impl Mie {
     pub fn mext(&self) -> bool {
         self.bits & (1 << 11) != 0
     }
+
+    /// Local Counter Overflow Interrupt Enable (Sscofpmf extension)
+    #[inline]
+    pub fn lcofie(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Platform-specific Local Interrupt Enable (bits 16-XLEN)
+    #[inline]
+    pub fn local(&self, index: usize) -> bool {
+        assert!((16..usize::BITS as usize).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+}
+
+impl InterruptEnableCsr for Mie {
+    const SOFT: InterruptFlags = InterruptFlags::MSOFT;
+    const TIMER: InterruptFlags = InterruptFlags::MTIMER;
+    const EXT: InterruptFlags = InterruptFlags::MEXT;
+
+    #[inline]
+    fn read() -> Self {
+        read()
+    }
+
+    #[inline]
+    fn flags(&self) -> InterruptFlags {
+        Mie::flags(self)
+    }
 }
 
 read_csr_as!(Mie, 0x304);
 set!(0x304);
 clear!(0x304);
 
+/// Enables all the interrupts in `flags` with a single CSR write.
+#[inline]
+pub unsafe fn enable(flags: InterruptFlags) {
+    _set(flags.bits());
+}
+
+/// Disables all the interrupts in `flags` with a single CSR write.
+#[inline]
+pub unsafe fn disable(flags: InterruptFlags) {
+    _clear(flags.bits());
+}
+
 set_clear_csr!(
     /// Supervisor Software Interrupt Enable
     , set_ssoft, clear_ssoft, 1 << 1);
@@ -72,3 +128,62 @@ set_clear_csr!(
 set_clear_csr!(
     /// Machine External Interrupt Enable
     , set_mext, clear_mext, 1 << 11);
+
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Enable
+    , set_lcofie, clear_lcofie, 1 << 13);
+
+/// Platform-specific Local Interrupt Enable (bits 16-XLEN)
+#[inline]
+pub unsafe fn set_local(index: usize) {
+    assert!((16..usize::BITS as usize).contains(&index));
+    _set(1 << index);
+}
+
+/// Platform-specific Local Interrupt Enable (bits 16-XLEN)
+#[inline]
+pub unsafe fn clear_local(index: usize) {
+    assert!((16..usize::BITS as usize).contains(&index));
+    _clear(1 << index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mie_local() {
+        let mut bits = 0;
+        assert!(!Mie::from(bits).local(16));
+        assert!(!Mie::from(bits).local(23));
+
+        bits |= 1 << 16;
+        let mie = Mie::from(bits);
+        assert!(mie.local(16));
+        assert!(!mie.local(23));
+
+        bits |= 1 << 23;
+        let mie = Mie::from(bits);
+        assert!(mie.local(16));
+        assert!(mie.local(23));
+
+        bits &= !(1 << 16);
+        let mie = Mie::from(bits);
+        assert!(!mie.local(16));
+        assert!(mie.local(23));
+    }
+
+    #[test]
+    fn test_mie_lcofie() {
+        assert!(!Mie::from(0).lcofie());
+        let mie = Mie::from(1 << 13);
+        assert!(mie.lcofie());
+        assert!(!mie.local(16));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mie_invalid_local() {
+        Mie::from(usize::MAX).local(11);
+    }
+}
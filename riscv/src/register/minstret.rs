@@ -2,3 +2,15 @@
 
 read_csr_as_usize!(0xB02);
 read_composite_csr!(super::minstreth::read(), read());
+write_csr!(0xB02);
+write_composite_csr!(super::minstreth::write, write);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// Any code measuring elapsed instructions with this counter is affected.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
@@ -0,0 +1,137 @@
+//! minstretcfg register (Smcntrpmf extension)
+
+/// minstretcfg register
+///
+/// It filters the privilege modes in which `minstret` counts.
+/// In RISCV-32, the filter bits are in the [`minstretcfgh`](super::minstretcfgh) register.
+#[derive(Clone, Copy, Debug)]
+pub struct Minstretcfg {
+    bits: usize,
+}
+
+impl From<usize> for Minstretcfg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Minstretcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Overflow status and interrupt disable
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn of(&self) -> bool {
+        self.bits & (1 << 63) != 0
+    }
+
+    /// Machine mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn minh(&self) -> bool {
+        self.bits & (1 << 62) != 0
+    }
+
+    /// Supervisor mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn sinh(&self) -> bool {
+        self.bits & (1 << 61) != 0
+    }
+
+    /// User mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn uinh(&self) -> bool {
+        self.bits & (1 << 60) != 0
+    }
+
+    /// Virtual Supervisor mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn vsinh(&self) -> bool {
+        self.bits & (1 << 59) != 0
+    }
+
+    /// Virtual User mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn vuinh(&self) -> bool {
+        self.bits & (1 << 58) != 0
+    }
+}
+
+read_csr_as!(Minstretcfg, 0x322);
+write_csr!(0x322);
+set!(0x322);
+clear!(0x322);
+
+set_clear_csr!(
+    /// Overflow status and interrupt disable
+    #[cfg(target_pointer_width = "64")]
+    , set_of, clear_of, 1 << 63);
+set_clear_csr!(
+    /// Machine mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    , set_minh, clear_minh, 1 << 62);
+set_clear_csr!(
+    /// Supervisor mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    , set_sinh, clear_sinh, 1 << 61);
+set_clear_csr!(
+    /// User mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    , set_uinh, clear_uinh, 1 << 60);
+set_clear_csr!(
+    /// Virtual Supervisor mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    , set_vsinh, clear_vsinh, 1 << 59);
+set_clear_csr!(
+    /// Virtual User mode counting Inhibit
+    #[cfg(target_pointer_width = "64")]
+    , set_vuinh, clear_vuinh, 1 << 58);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_minstretcfg() {
+        let mut bits = 0;
+        let minstretcfg = Minstretcfg::from(bits);
+        assert!(!minstretcfg.of());
+        assert!(!minstretcfg.minh());
+        assert!(!minstretcfg.sinh());
+        assert!(!minstretcfg.uinh());
+        assert!(!minstretcfg.vsinh());
+        assert!(!minstretcfg.vuinh());
+
+        // count only in M-mode
+        bits |= (1 << 61) | (1 << 60) | (1 << 59) | (1 << 58);
+        let minstretcfg = Minstretcfg::from(bits);
+        assert!(!minstretcfg.minh());
+        assert!(minstretcfg.sinh());
+        assert!(minstretcfg.uinh());
+        assert!(minstretcfg.vsinh());
+        assert!(minstretcfg.vuinh());
+
+        // count only in U-mode and VU-mode
+        bits ^= (1 << 62) | (1 << 60) | (1 << 58);
+        let minstretcfg = Minstretcfg::from(bits);
+        assert!(minstretcfg.minh());
+        assert!(minstretcfg.sinh());
+        assert!(!minstretcfg.uinh());
+        assert!(minstretcfg.vsinh());
+        assert!(!minstretcfg.vuinh());
+        assert!(!minstretcfg.of());
+
+        bits |= 1 << 63;
+        assert!(Minstretcfg::from(bits).of());
+    }
+}
@@ -0,0 +1,84 @@
+//! minstretcfgh register (Smcntrpmf extension, RISCV-32 only)
+
+/// minstretcfgh register
+///
+/// It holds the privilege mode filter bits of [`minstretcfg`](super::minstretcfg) in RISCV-32.
+#[derive(Clone, Copy, Debug)]
+pub struct Minstretcfgh {
+    bits: usize,
+}
+
+impl From<usize> for Minstretcfgh {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Minstretcfgh {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Overflow status and interrupt disable
+    #[inline]
+    pub fn of(&self) -> bool {
+        self.bits & (1 << 31) != 0
+    }
+
+    /// Machine mode counting Inhibit
+    #[inline]
+    pub fn minh(&self) -> bool {
+        self.bits & (1 << 30) != 0
+    }
+
+    /// Supervisor mode counting Inhibit
+    #[inline]
+    pub fn sinh(&self) -> bool {
+        self.bits & (1 << 29) != 0
+    }
+
+    /// User mode counting Inhibit
+    #[inline]
+    pub fn uinh(&self) -> bool {
+        self.bits & (1 << 28) != 0
+    }
+
+    /// Virtual Supervisor mode counting Inhibit
+    #[inline]
+    pub fn vsinh(&self) -> bool {
+        self.bits & (1 << 27) != 0
+    }
+
+    /// Virtual User mode counting Inhibit
+    #[inline]
+    pub fn vuinh(&self) -> bool {
+        self.bits & (1 << 26) != 0
+    }
+}
+
+read_csr_as_rv32!(Minstretcfgh, 0x722);
+write_csr_rv32!(0x722);
+set_rv32!(0x722);
+clear_rv32!(0x722);
+
+set_clear_csr!(
+    /// Overflow status and interrupt disable
+    , set_of, clear_of, 1 << 31);
+set_clear_csr!(
+    /// Machine mode counting Inhibit
+    , set_minh, clear_minh, 1 << 30);
+set_clear_csr!(
+    /// Supervisor mode counting Inhibit
+    , set_sinh, clear_sinh, 1 << 29);
+set_clear_csr!(
+    /// User mode counting Inhibit
+    , set_uinh, clear_uinh, 1 << 28);
+set_clear_csr!(
+    /// Virtual Supervisor mode counting Inhibit
+    , set_vsinh, clear_vsinh, 1 << 27);
+set_clear_csr!(
+    /// Virtual User mode counting Inhibit
+    , set_vuinh, clear_vuinh, 1 << 26);
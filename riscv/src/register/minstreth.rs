@@ -1,3 +1,14 @@
 //! minstreth register
 
 read_csr_as_usize_rv32!(0xB82);
+write_csr_rv32!(0xB82);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// Any code measuring elapsed instructions with this counter is affected.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
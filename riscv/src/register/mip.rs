@@ -6,6 +6,13 @@ pub struct Mip {
     bits: usize,
 }
 
+impl From<usize> for Mip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Mip {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -48,6 +55,19 @@ impl Mip {
     pub fn mext(&self) -> bool {
         self.bits & (1 << 11) != 0
     }
+
+    /// Local Counter Overflow Interrupt Pending (Sscofpmf extension)
+    #[inline]
+    pub fn lcofip(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Platform-specific Local Interrupt Pending (bits 16-XLEN)
+    #[inline]
+    pub fn local(&self, index: usize) -> bool {
+        assert!((16..usize::BITS as usize).contains(&index));
+        self.bits & (1 << index) != 0
+    }
 }
 
 read_csr_as!(Mip, 0x344);
@@ -63,3 +83,62 @@ set_clear_csr!(
 set_clear_csr!(
     /// Supervisor External Interrupt Pending
     , set_sext, clear_sext, 1 << 9);
+
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Pending
+    , set_lcofip, clear_lcofip, 1 << 13);
+
+/// Platform-specific Local Interrupt Pending (bits 16-XLEN)
+#[inline]
+pub unsafe fn set_local(index: usize) {
+    assert!((16..usize::BITS as usize).contains(&index));
+    _set(1 << index);
+}
+
+/// Platform-specific Local Interrupt Pending (bits 16-XLEN)
+#[inline]
+pub unsafe fn clear_local(index: usize) {
+    assert!((16..usize::BITS as usize).contains(&index));
+    _clear(1 << index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mip_local() {
+        let mut bits = 0;
+        assert!(!Mip::from(bits).local(16));
+        assert!(!Mip::from(bits).local(23));
+
+        bits |= 1 << 16;
+        let mip = Mip::from(bits);
+        assert!(mip.local(16));
+        assert!(!mip.local(23));
+
+        bits |= 1 << 23;
+        let mip = Mip::from(bits);
+        assert!(mip.local(16));
+        assert!(mip.local(23));
+
+        bits &= !(1 << 16);
+        let mip = Mip::from(bits);
+        assert!(!mip.local(16));
+        assert!(mip.local(23));
+    }
+
+    #[test]
+    fn test_mip_lcofip() {
+        assert!(!Mip::from(0).lcofip());
+        let mip = Mip::from(1 << 13);
+        assert!(mip.lcofip());
+        assert!(!mip.local(16));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mip_invalid_local() {
+        Mip::from(usize::MAX).local(11);
+    }
+}
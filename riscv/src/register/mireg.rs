@@ -0,0 +1,176 @@
+//! mireg register (Smcsrind extension)
+//!
+//! It gives access to the indirect register selected by [`crate::register::miselect`].
+
+read_csr_as_usize!(0x351);
+write_csr_as_usize!(0x351);
+
+/// Reads the indirect register selected by `select`.
+///
+/// It writes `select` to the `miselect` CSR and then reads the `mireg` CSR.
+///
+/// # Safety
+///
+/// The sequence is not atomic. An interrupt handler that changes `miselect`
+/// between both accesses makes this function read a different indirect register.
+/// Disable interrupts or make sure that handlers restore `miselect` before returning.
+#[inline]
+pub unsafe fn read_indirect(select: usize) -> usize {
+    read_indirect_with(select, super::miselect::write, read)
+}
+
+/// Writes `value` to the indirect register selected by `select`.
+///
+/// It writes `select` to the `miselect` CSR and then writes `value` to the `mireg` CSR.
+///
+/// # Safety
+///
+/// The sequence is not atomic. An interrupt handler that changes `miselect`
+/// between both accesses makes this function write a different indirect register.
+/// Disable interrupts or make sure that handlers restore `miselect` before returning.
+#[inline]
+pub unsafe fn write_indirect(select: usize, value: usize) {
+    write_indirect_with(select, value, super::miselect::write, write);
+}
+
+/// Same as [`read_indirect`], but using the provided functions to access the CSRs.
+#[inline]
+fn read_indirect_with<S: FnOnce(usize), R: FnOnce() -> usize>(
+    select: usize,
+    write_select: S,
+    read_reg: R,
+) -> usize {
+    write_select(select);
+    read_reg()
+}
+
+/// Same as [`write_indirect`], but using the provided functions to access the CSRs.
+#[inline]
+fn write_indirect_with<S: FnOnce(usize), W: FnOnce(usize)>(
+    select: usize,
+    value: usize,
+    write_select: S,
+    write_reg: W,
+) {
+    write_select(select);
+    write_reg(value);
+}
+
+/// `miselect` value of the first `iprio` register (AIA)
+const IPRIO0: usize = 0x30;
+
+/// Returns the `miselect` value and the byte offset within `mireg`
+/// of the priority of the major interrupt `irq` (AIA).
+///
+/// Each `iprio` register packs one priority byte per interrupt.
+/// In RV64, only the even-numbered `iprio` registers exist.
+#[inline]
+const fn iprio_location(irq: usize) -> (usize, usize) {
+    assert!(irq < 64);
+    let per_reg = usize::BITS as usize / 8;
+    (IPRIO0 + (irq / per_reg) * (per_reg / 4), irq % per_reg)
+}
+
+/// Reads the priority of the major interrupt `irq` from the `iprio` array (AIA).
+///
+/// # Panics
+///
+/// It panics if `irq` is not in the range 0-63.
+///
+/// # Safety
+///
+/// It accesses `mireg` through [`read_indirect`]. See its documentation for more information.
+#[inline]
+pub unsafe fn get_iprio(irq: usize) -> u8 {
+    let (select, byte) = iprio_location(irq);
+    (read_indirect(select) >> (byte * 8)) as u8
+}
+
+/// Writes the priority of the major interrupt `irq` to the `iprio` array (AIA).
+///
+/// It performs a read-modify-write of the `iprio` register, leaving the other priorities untouched.
+///
+/// # Panics
+///
+/// It panics if `irq` is not in the range 0-63.
+///
+/// # Safety
+///
+/// It accesses `mireg` through [`read_indirect`] and [`write_indirect`].
+/// See their documentation for more information.
+/// Additionally, changing interrupt priorities may break priority-based critical sections.
+#[inline]
+pub unsafe fn set_iprio(irq: usize, priority: u8) {
+    let (select, byte) = iprio_location(irq);
+    let mut value = read_indirect(select);
+    value &= !(0xFF << (byte * 8));
+    value |= (priority as usize) << (byte * 8);
+    write_indirect(select, value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_read_indirect() {
+        // `None` until miselect is written, and then the selected register
+        let selected = Cell::new(None);
+        let value = read_indirect_with(
+            0x30,
+            |s| {
+                assert_eq!(selected.get(), None);
+                selected.set(Some(s));
+            },
+            || {
+                assert_eq!(selected.get(), Some(0x30), "mireg accessed before miselect");
+                0xAA
+            },
+        );
+        assert_eq!(value, 0xAA);
+    }
+
+    #[test]
+    fn test_write_indirect() {
+        let selected = Cell::new(None);
+        let written = Cell::new(None);
+        write_indirect_with(
+            0x30,
+            0x55,
+            |s| {
+                assert_eq!((selected.get(), written.get()), (None, None));
+                selected.set(Some(s));
+            },
+            |v| {
+                assert_eq!(selected.get(), Some(0x30), "mireg accessed before miselect");
+                written.set(Some(v));
+            },
+        );
+        assert_eq!(written.get(), Some(0x55));
+    }
+
+    #[test]
+    fn test_iprio_location() {
+        assert_eq!(iprio_location(0), (0x30, 0));
+        assert_eq!(iprio_location(3), (0x30, 3));
+        #[cfg(target_pointer_width = "32")]
+        {
+            assert_eq!(iprio_location(4), (0x31, 0));
+            assert_eq!(iprio_location(13), (0x33, 1));
+            assert_eq!(iprio_location(63), (0x3F, 3));
+        }
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(iprio_location(4), (0x30, 4));
+            assert_eq!(iprio_location(13), (0x32, 5));
+            assert_eq!(iprio_location(63), (0x3E, 7));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iprio_location_invalid() {
+        iprio_location(64);
+    }
+}
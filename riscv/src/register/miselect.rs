@@ -0,0 +1,4 @@
+//! miselect register (Smcsrind extension)
+
+read_csr_as_usize!(0x350);
+write_csr_as_usize!(0x350);
@@ -0,0 +1,4 @@
+//! mncause register (Smrnmi extension)
+
+read_csr_as_usize!(0x742);
+write_csr_as_usize!(0x742);
@@ -0,0 +1,4 @@
+//! mnepc register (Smrnmi extension)
+
+read_csr_as_usize!(0x741);
+write_csr_as_usize!(0x741);
@@ -0,0 +1,94 @@
+//! mnstatus register (Smrnmi extension)
+
+pub use super::mstatus::MPP;
+
+/// mnstatus register
+#[derive(Clone, Copy, Debug)]
+pub struct Mnstatus {
+    bits: usize,
+}
+
+impl From<usize> for Mnstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mnstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// NMI Enable
+    #[inline]
+    pub fn nmie(&self) -> bool {
+        self.bits & (1 << 3) != 0
+    }
+
+    /// Previous Virtualization Mode (Hypervisor extension)
+    #[inline]
+    pub fn mnpv(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+
+    /// Previous Privilege Mode
+    #[inline]
+    pub fn mnpp(&self) -> MPP {
+        let mnpp = (self.bits >> 11) & 0x3; // bits 11-12
+        match mnpp {
+            0b00 => MPP::User,
+            0b01 => MPP::Supervisor,
+            0b11 => MPP::Machine,
+            _ => unreachable!(),
+        }
+    }
+}
+
+read_csr_as!(Mnstatus, 0x744);
+write_csr!(0x744);
+set!(0x744);
+clear!(0x744);
+
+set_csr!(
+    /// NMI Enable
+    ///
+    /// Once set, `NMIE` can only be cleared by hardware when taking an NMI.
+    , set_nmie, 1 << 3);
+set_clear_csr!(
+    /// Previous Virtualization Mode
+    , set_mnpv, clear_mnpv, 1 << 7);
+
+/// Previous Privilege Mode
+#[inline]
+pub unsafe fn set_mnpp(mnpp: MPP) {
+    let mut value = _read();
+    value &= !(0x3 << 11); // clear previous value
+    value |= (mnpp as usize) << 11;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mnstatus() {
+        let mnstatus = Mnstatus::from(0);
+        assert!(!mnstatus.nmie());
+        assert!(!mnstatus.mnpv());
+        assert_eq!(mnstatus.mnpp(), MPP::User);
+
+        let mnstatus = Mnstatus::from((1 << 3) | (0b01 << 11));
+        assert!(mnstatus.nmie());
+        assert!(!mnstatus.mnpv());
+        assert_eq!(mnstatus.mnpp(), MPP::Supervisor);
+
+        let mnstatus = Mnstatus::from((1 << 7) | (0b11 << 11));
+        assert!(!mnstatus.nmie());
+        assert!(mnstatus.mnpv());
+        assert_eq!(mnstatus.mnpp(), MPP::Machine);
+    }
+}
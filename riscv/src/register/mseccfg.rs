@@ -0,0 +1,156 @@
+//! mseccfg register
+
+pub use super::henvcfg::PointerMaskingMode;
+
+/// mseccfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Mseccfg {
+    bits: usize,
+}
+
+impl From<usize> for Mseccfg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mseccfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Machine Mode Lockdown (Smepmp extension)
+    #[inline]
+    pub fn mml(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// Machine Mode Whitelist Policy (Smepmp extension)
+    #[inline]
+    pub fn mmwp(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Rule Locking Bypass (Smepmp extension)
+    #[inline]
+    pub fn rlb(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// U-mode `seed` CSR access Enable (Zkr extension)
+    #[inline]
+    pub fn useed(&self) -> bool {
+        self.bits & (1 << 8) != 0
+    }
+
+    /// S-mode `seed` CSR access Enable (Zkr extension)
+    #[inline]
+    pub fn sseed(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+
+    /// M-mode Landing Pad Enable (Zicfilp extension)
+    #[inline]
+    pub fn mlpe(&self) -> bool {
+        self.bits & (1 << 10) != 0
+    }
+
+    /// Pointer Masking Mode for M-mode (Smmpm extension)
+    ///
+    /// Pointer masking is only supported in RISCV-64.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn pmm(&self) -> Option<PointerMaskingMode> {
+        match (self.bits >> 32) & 0x3 {
+            0b00 => Some(PointerMaskingMode::Disabled),
+            0b10 => Some(PointerMaskingMode::Bits7),
+            0b11 => Some(PointerMaskingMode::Bits16),
+            _ => None,
+        }
+    }
+}
+
+read_csr_as!(Mseccfg, 0x747);
+write_csr!(0x747);
+set!(0x747);
+clear!(0x747);
+
+set_csr!(
+    /// Machine Mode Lockdown
+    ///
+    /// It enables the [`crate::register::MmlPermission`] semantics for PMP entries, and
+    /// denies M-mode execution from memory regions that are not covered by a locked PMP entry.
+    /// Once set, `MML` can only be cleared by a reset.
+    , set_mml, 1 << 0);
+set_csr!(
+    /// Machine Mode Whitelist Policy
+    ///
+    /// It denies M-mode accesses to memory regions that are not covered by any PMP entry.
+    /// Once set, `MMWP` can only be cleared by a reset.
+    , set_mmwp, 1 << 1);
+set_clear_csr!(
+    /// Rule Locking Bypass
+    ///
+    /// While set, locked PMP entries can be modified. It can only be set if no PMP entry is locked.
+    , set_rlb, clear_rlb, 1 << 2);
+set_clear_csr!(
+    /// U-mode `seed` CSR access Enable
+    , set_useed, clear_useed, 1 << 8);
+set_clear_csr!(
+    /// S-mode `seed` CSR access Enable
+    , set_sseed, clear_sseed, 1 << 9);
+set_clear_csr!(
+    /// M-mode Landing Pad Enable
+    , set_mlpe, clear_mlpe, 1 << 10);
+
+/// Pointer Masking Mode
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn set_pmm(pmm: PointerMaskingMode) {
+    let mut value = _read();
+    value &= !(0x3 << 32); // clear previous value
+    value |= (pmm as usize) << 32;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mseccfg() {
+        let mseccfg = Mseccfg::from(0);
+        assert!(!mseccfg.mml());
+        assert!(!mseccfg.mmwp());
+        assert!(!mseccfg.rlb());
+        assert!(!mseccfg.useed());
+        assert!(!mseccfg.sseed());
+        assert!(!mseccfg.mlpe());
+
+        let mseccfg = Mseccfg::from((1 << 0) | (1 << 2) | (1 << 9));
+        assert!(mseccfg.mml());
+        assert!(!mseccfg.mmwp());
+        assert!(mseccfg.rlb());
+        assert!(!mseccfg.useed());
+        assert!(mseccfg.sseed());
+        assert!(!mseccfg.mlpe());
+
+        let mseccfg = Mseccfg::from((1 << 10) | (1 << 9));
+        assert!(mseccfg.mlpe());
+        assert!(mseccfg.sseed());
+        assert!(!mseccfg.mml());
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(mseccfg.pmm(), Some(PointerMaskingMode::Disabled));
+            assert_eq!(
+                Mseccfg::from(0b10 << 32).pmm(),
+                Some(PointerMaskingMode::Bits7)
+            );
+            assert_eq!(Mseccfg::from(0b01 << 32).pmm(), None);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! mstateen0 register (Smstateen extension)
+
+/// mstateen0 register
+#[derive(Clone, Copy, Debug)]
+pub struct Mstateen0 {
+    bits: usize,
+}
+
+impl From<usize> for Mstateen0 {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mstateen0 {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Custom state enable
+    #[inline]
+    pub fn c(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// `fcsr` access enable (for harts with `misa.F` = 0, e.g. Zfinx)
+    #[inline]
+    pub fn fcsr(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// `jvt` access enable (Zcmt extension)
+    #[inline]
+    pub fn jvt(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// IMSIC state enable (Ssaia extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::mstateen0h`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn imsic(&self) -> bool {
+        self.bits & (1 << 58) != 0
+    }
+
+    /// AIA state enable (Ssaia extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::mstateen0h`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn aia(&self) -> bool {
+        self.bits & (1 << 59) != 0
+    }
+
+    /// `siselect`/`sireg*` access enable (Sscsrind extension)
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::mstateen0h`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn csrind(&self) -> bool {
+        self.bits & (1 << 60) != 0
+    }
+
+    /// `henvcfg`/`senvcfg` access enable
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::mstateen0h`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn envcfg(&self) -> bool {
+        self.bits & (1 << 62) != 0
+    }
+
+    /// `hstateen0`/`sstateen0` access enable
+    ///
+    /// In RISCV-32, this field is available in the [`crate::register::mstateen0h`] register
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn se0(&self) -> bool {
+        self.bits & (1 << 63) != 0
+    }
+}
+
+read_csr_as!(Mstateen0, 0x30C);
+write_csr!(0x30C);
+set!(0x30C);
+clear!(0x30C);
+
+set_clear_csr!(
+    /// Custom state enable
+    , set_c, clear_c, 1 << 0);
+set_clear_csr!(
+    /// `fcsr` access enable
+    , set_fcsr, clear_fcsr, 1 << 1);
+set_clear_csr!(
+    /// `jvt` access enable
+    , set_jvt, clear_jvt, 1 << 2);
+set_clear_csr!(
+    /// IMSIC state enable
+    #[cfg(target_pointer_width = "64")]
+    , set_imsic, clear_imsic, 1 << 58);
+set_clear_csr!(
+    /// AIA state enable
+    #[cfg(target_pointer_width = "64")]
+    , set_aia, clear_aia, 1 << 59);
+set_clear_csr!(
+    /// `siselect`/`sireg*` access enable
+    #[cfg(target_pointer_width = "64")]
+    , set_csrind, clear_csrind, 1 << 60);
+set_clear_csr!(
+    /// `henvcfg`/`senvcfg` access enable
+    #[cfg(target_pointer_width = "64")]
+    , set_envcfg, clear_envcfg, 1 << 62);
+set_clear_csr!(
+    /// `hstateen0`/`sstateen0` access enable
+    #[cfg(target_pointer_width = "64")]
+    , set_se0, clear_se0, 1 << 63);
+
+/// State-enable bit of `mstateen0` that gates access to a CSR.
+///
+/// When a state-enable bit is clear, lower privilege modes accessing the CSRs that it gates
+/// raise an illegal instruction exception. Use [`StateEnable::missing`] in a trap handler
+/// to find out whether a state-enable misconfiguration caused the trap.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StateEnable {
+    /// `fcsr`, `frm`, and `fflags` access enable (bit 1)
+    Fcsr = 1,
+    /// `jvt` access enable (bit 2)
+    Jvt = 2,
+    /// IMSIC state enable (bit 58)
+    Imsic = 58,
+    /// AIA state enable (bit 59)
+    Aia = 59,
+    /// `siselect`/`sireg*` access enable (bit 60)
+    Csrind = 60,
+    /// `henvcfg`/`senvcfg` access enable (bit 62)
+    Envcfg = 62,
+    /// `hstateen0`/`sstateen0` access enable (bit 63)
+    Se0 = 63,
+}
+
+impl StateEnable {
+    /// Returns the state-enable bit that gates access to the CSR with address `csr`.
+    ///
+    /// It returns [`None`] if the CSR is not gated by any bit of `mstateen0`.
+    #[inline]
+    pub const fn from_csr(csr: usize) -> Option<Self> {
+        match csr {
+            0x001..=0x003 => Some(Self::Fcsr), // fflags, frm, fcsr
+            0x017 => Some(Self::Jvt),
+            0x15C | 0x25C => Some(Self::Imsic), // stopei, vstopei
+            0x114 | 0x154 | 0x214 | 0x254 => Some(Self::Aia), // sieh, siph, vsieh, vsiph
+            0x608 | 0x609 | 0x613 | 0x618 => Some(Self::Aia), // hvien, hvictl, hidelegh, hvienh
+            0x646 | 0x647 | 0x655..=0x657 => Some(Self::Aia), // hviprio1/2, hviph, hviprio1h/2h
+            0xDB0 | 0xEB0 => Some(Self::Aia),   // stopi, vstopi
+            0x150..=0x157 | 0x250..=0x257 => Some(Self::Csrind), // siselect, sireg*, vsiselect, vsireg*
+            0x10A | 0x60A | 0x61A => Some(Self::Envcfg),         // senvcfg, henvcfg, henvcfgh
+            0x10C | 0x60C | 0x61C => Some(Self::Se0),            // sstateen0, hstateen0, hstateen0h
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the bit in the 64-bit `mstateen0` register.
+    ///
+    /// In RISCV-32, bits 32-63 are located in the `mstateen0h` register.
+    #[inline]
+    pub const fn bit(self) -> usize {
+        self as usize
+    }
+
+    /// Returns `true` if the state-enable bit is set in `mstateen0`.
+    #[inline]
+    pub fn is_enabled(self) -> bool {
+        read_mstateen0() & (1 << self.bit()) != 0
+    }
+
+    /// Returns the state-enable bit that was missing when an access to the CSR `csr` trapped.
+    ///
+    /// It returns [`None`] if `cause` is not an illegal instruction exception,
+    /// if `csr` is not gated by `mstateen0`, or if the corresponding bit is set.
+    /// In the latter case, the trap was caused by something else (e.g., insufficient privilege).
+    ///
+    /// # Note
+    ///
+    /// For illegal instruction exceptions, `mtval` may hold the faulting instruction.
+    /// If so, the address of the CSR is in its bits 20-31.
+    #[inline]
+    pub fn missing(cause: super::mcause::Trap, csr: usize) -> Option<Self> {
+        Self::missing_with(cause, csr, read_mstateen0)
+    }
+
+    /// Same as [`StateEnable::missing`], but reading `mstateen0` with `read_mstateen0`.
+    #[inline]
+    fn missing_with<F: FnOnce() -> u64>(
+        cause: super::mcause::Trap,
+        csr: usize,
+        read_mstateen0: F,
+    ) -> Option<Self> {
+        use super::mcause::{Exception, Trap};
+
+        if cause != Trap::Exception(Exception::IllegalInstruction) {
+            return None;
+        }
+        match Self::from_csr(csr) {
+            Some(se) if read_mstateen0() & (1 << se.bit()) == 0 => Some(se),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the 64 bits of `mstateen0` (and `mstateen0h` in RISCV-32).
+#[inline]
+fn read_mstateen0() -> u64 {
+    match () {
+        #[cfg(riscv32)]
+        () => ((super::mstateen0h::read().bits() as u64) << 32) | read().bits() as u64,
+        #[cfg(not(riscv32))]
+        () => read().bits() as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mstateen0() {
+        let mstateen0 = Mstateen0::from(0);
+        assert!(!mstateen0.c());
+        assert!(!mstateen0.fcsr());
+        assert!(!mstateen0.jvt());
+
+        let mstateen0 = Mstateen0::from(0b101);
+        assert!(mstateen0.c());
+        assert!(!mstateen0.fcsr());
+        assert!(mstateen0.jvt());
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let mstateen0 = Mstateen0::from((1 << 63) | (1 << 59));
+            assert!(mstateen0.se0());
+            assert!(mstateen0.aia());
+            assert!(!mstateen0.imsic());
+            assert!(!mstateen0.csrind());
+            assert!(!mstateen0.envcfg());
+            assert!(!mstateen0.c());
+        }
+    }
+
+    #[test]
+    fn test_state_enable_from_csr() {
+        assert_eq!(StateEnable::from_csr(0x003), Some(StateEnable::Fcsr)); // fcsr
+        assert_eq!(StateEnable::from_csr(0x017), Some(StateEnable::Jvt)); // jvt
+        assert_eq!(StateEnable::from_csr(0x150), Some(StateEnable::Csrind)); // siselect
+        assert_eq!(StateEnable::from_csr(0x251), Some(StateEnable::Csrind)); // vsireg
+        assert_eq!(StateEnable::from_csr(0x154), Some(StateEnable::Aia)); // siph
+        assert_eq!(StateEnable::from_csr(0xDB0), Some(StateEnable::Aia)); // stopi
+        assert_eq!(StateEnable::from_csr(0x15C), Some(StateEnable::Imsic)); // stopei
+        assert_eq!(StateEnable::from_csr(0x10A), Some(StateEnable::Envcfg)); // senvcfg
+        assert_eq!(StateEnable::from_csr(0x10C), Some(StateEnable::Se0)); // sstateen0
+        assert_eq!(StateEnable::from_csr(0x100), None); // sstatus
+        assert_eq!(StateEnable::from_csr(0x300), None); // mstatus
+
+        assert_eq!(StateEnable::Jvt.bit(), 2);
+        assert_eq!(StateEnable::Envcfg.bit(), 62);
+    }
+
+    #[test]
+    fn test_state_enable_missing() {
+        use crate::register::mcause::{Exception, Interrupt, Trap};
+
+        let illegal = Trap::Exception(Exception::IllegalInstruction);
+
+        // jvt is gated and its state-enable bit is clear
+        let missing = StateEnable::missing_with(illegal, 0x017, || 0);
+        assert_eq!(missing, Some(StateEnable::Jvt));
+        // jvt is gated, but its state-enable bit is set
+        assert_eq!(StateEnable::missing_with(illegal, 0x017, || 1 << 2), None);
+        // senvcfg is gated by bit 62
+        let missing = StateEnable::missing_with(illegal, 0x10A, || 1 << 2);
+        assert_eq!(missing, Some(StateEnable::Envcfg));
+        assert_eq!(StateEnable::missing_with(illegal, 0x10A, || 1 << 62), None);
+        // sstatus is not gated by mstateen0
+        assert_eq!(StateEnable::missing_with(illegal, 0x100, || 0), None);
+
+        // other traps are not caused by state-enable bits
+        let fault = Trap::Exception(Exception::LoadFault);
+        assert_eq!(StateEnable::missing_with(fault, 0x017, || 0), None);
+        let timer = Trap::Interrupt(Interrupt::MachineTimer);
+        assert_eq!(StateEnable::missing_with(timer, 0x017, || 0), None);
+    }
+}
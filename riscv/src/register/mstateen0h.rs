@@ -0,0 +1,88 @@
+//! mstateen0h register (Smstateen extension, RISCV-32 only)
+
+/// mstateen0h register
+#[derive(Clone, Copy, Debug)]
+pub struct Mstateen0h {
+    bits: usize,
+}
+
+impl From<usize> for Mstateen0h {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mstateen0h {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// IMSIC state enable (Ssaia extension)
+    #[inline]
+    pub fn imsic(&self) -> bool {
+        self.bits & (1 << 26) != 0
+    }
+
+    /// AIA state enable (Ssaia extension)
+    #[inline]
+    pub fn aia(&self) -> bool {
+        self.bits & (1 << 27) != 0
+    }
+
+    /// `siselect`/`sireg*` access enable (Sscsrind extension)
+    #[inline]
+    pub fn csrind(&self) -> bool {
+        self.bits & (1 << 28) != 0
+    }
+
+    /// `henvcfg`/`senvcfg` access enable
+    #[inline]
+    pub fn envcfg(&self) -> bool {
+        self.bits & (1 << 30) != 0
+    }
+
+    /// `hstateen0`/`sstateen0` access enable
+    #[inline]
+    pub fn se0(&self) -> bool {
+        self.bits & (1 << 31) != 0
+    }
+}
+
+read_csr_as_rv32!(Mstateen0h, 0x31C);
+write_csr_rv32!(0x31C);
+set_rv32!(0x31C);
+clear_rv32!(0x31C);
+
+set_clear_csr!(
+    /// IMSIC state enable
+    , set_imsic, clear_imsic, 1 << 26);
+set_clear_csr!(
+    /// AIA state enable
+    , set_aia, clear_aia, 1 << 27);
+set_clear_csr!(
+    /// `siselect`/`sireg*` access enable
+    , set_csrind, clear_csrind, 1 << 28);
+set_clear_csr!(
+    /// `henvcfg`/`senvcfg` access enable
+    , set_envcfg, clear_envcfg, 1 << 30);
+set_clear_csr!(
+    /// `hstateen0`/`sstateen0` access enable
+    , set_se0, clear_se0, 1 << 31);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mstateen0h() {
+        let mstateen0h = Mstateen0h::from((1 << 31) | (1 << 26));
+        assert!(mstateen0h.se0());
+        assert!(mstateen0h.imsic());
+        assert!(!mstateen0h.aia());
+        assert!(!mstateen0h.csrind());
+        assert!(!mstateen0h.envcfg());
+    }
+}
@@ -0,0 +1,31 @@
+macro_rules! reg {
+    (
+        $addr:expr, $csr:ident
+    ) => {
+        /// Machine state enable register (Smstateen extension)
+        pub mod $csr {
+            read_csr_as_usize!($addr);
+            write_csr_as_usize!($addr);
+        }
+    };
+}
+
+macro_rules! regh {
+    (
+        $addr:expr, $csrh:ident
+    ) => {
+        /// Upper 32 bits of machine state enable register (RV32I only)
+        pub mod $csrh {
+            read_csr_as_usize_rv32!($addr);
+            write_csr_as_usize_rv32!($addr);
+        }
+    };
+}
+
+reg!(0x30D, mstateen1);
+reg!(0x30E, mstateen2);
+reg!(0x30F, mstateen3);
+
+regh!(0x31D, mstateen1h);
+regh!(0x31E, mstateen2h);
+regh!(0x31F, mstateen3h);
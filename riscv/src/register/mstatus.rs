@@ -145,6 +145,45 @@ impl Mstatus {
         }
     }
 
+    /// Vector extension state
+    ///
+    /// Encodes the status of the vector unit, including the vector CSRs and vector registers `v0–v31`.
+    #[inline]
+    pub fn vs(&self) -> FS {
+        let vs = (self.bits >> 9) & 0x3; // bits 9-10
+        match vs {
+            0b00 => FS::Off,
+            0b01 => FS::Initial,
+            0b10 => FS::Clean,
+            0b11 => FS::Dirty,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns `true` if the floating-point state is dirty and must be saved on context switches
+    #[inline]
+    pub fn fp_dirty(&self) -> bool {
+        self.fs() == FS::Dirty
+    }
+
+    /// Returns `true` if the vector state is dirty and must be saved on context switches
+    #[inline]
+    pub fn vector_dirty(&self) -> bool {
+        self.vs() == FS::Dirty
+    }
+
+    /// Returns a copy of the register with the floating-point extension state set to `fs`
+    #[inline]
+    fn with_fs(self, fs: FS) -> Self {
+        Self::from((self.bits & !(0x3 << 13)) | ((fs as usize) << 13))
+    }
+
+    /// Returns a copy of the register with the vector extension state set to `vs`
+    #[inline]
+    fn with_vs(self, vs: FS) -> Self {
+        Self::from((self.bits & !(0x3 << 9)) | ((vs as usize) << 9))
+    }
+
     /// Additional extension state
     ///
     /// Encodes the status of additional user-mode extensions and associated state.
@@ -264,6 +303,44 @@ impl Mstatus {
         }
     }
 
+    /// S-mode Previous Expected Landing Pad state (Zicfilp extension)
+    #[inline]
+    pub fn spelp(&self) -> bool {
+        self.bits & (1 << 23) != 0
+    }
+
+    /// M-mode Previous Expected Landing Pad state (Zicfilp extension)
+    ///
+    /// In RISCV-32, this field is read from the [`crate::register::mstatush`] register
+    #[inline]
+    pub fn mpelp(&self) -> bool {
+        match () {
+            #[cfg(riscv32)]
+            () => super::mstatush::read().mpelp(),
+            #[cfg(not(riscv32))]
+            () => self.bits & (1 << 41) != 0,
+        }
+    }
+
+    /// S-mode Disable Trap (Ssdbltrp extension)
+    #[inline]
+    pub fn sdt(&self) -> bool {
+        self.bits & (1 << 24) != 0
+    }
+
+    /// M-mode Disable Trap (Smdbltrp extension)
+    ///
+    /// In RISCV-32, this field is read from the [`crate::register::mstatush`] register
+    #[inline]
+    pub fn mdt(&self) -> bool {
+        match () {
+            #[cfg(riscv32)]
+            () => super::mstatush::read().mdt(),
+            #[cfg(not(riscv32))]
+            () => self.bits & (1 << 42) != 0,
+        }
+    }
+
     /// Whether either the FS field or XS field signals the presence of some dirty state
     #[inline]
     pub fn sd(&self) -> bool {
@@ -312,6 +389,72 @@ set_clear_csr!(
 set_clear_csr!(
     /// Trap SRET
     , set_tsr, clear_tsr, 1 << 22);
+set_clear_csr!(
+    /// S-mode Previous Expected Landing Pad state
+    , set_spelp, clear_spelp, 1 << 23);
+set_clear_csr!(
+    /// S-mode Disable Trap
+    , set_sdt, clear_sdt, 1 << 24);
+
+/// Set M-mode Disable Trap
+///
+/// # Note
+///
+/// In RISCV-32, this function calls [`crate::register::mstatush::set_mdt`]
+#[inline]
+pub unsafe fn set_mdt() {
+    match () {
+        #[cfg(riscv32)]
+        () => super::mstatush::set_mdt(),
+        #[cfg(not(riscv32))]
+        () => _set(1 << 42),
+    }
+}
+
+/// Clear M-mode Disable Trap
+///
+/// # Note
+///
+/// In RISCV-32, this function calls [`crate::register::mstatush::clear_mdt`]
+#[inline]
+pub unsafe fn clear_mdt() {
+    match () {
+        #[cfg(riscv32)]
+        () => super::mstatush::clear_mdt(),
+        #[cfg(not(riscv32))]
+        () => _clear(1 << 42),
+    }
+}
+
+/// Set M-mode Previous Expected Landing Pad state
+///
+/// # Note
+///
+/// In RISCV-32, this function calls [`crate::register::mstatush::set_mpelp`]
+#[inline]
+pub unsafe fn set_mpelp() {
+    match () {
+        #[cfg(riscv32)]
+        () => super::mstatush::set_mpelp(),
+        #[cfg(not(riscv32))]
+        () => _set(1 << 41),
+    }
+}
+
+/// Clear M-mode Previous Expected Landing Pad state
+///
+/// # Note
+///
+/// In RISCV-32, this function calls [`crate::register::mstatush::clear_mpelp`]
+#[inline]
+pub unsafe fn clear_mpelp() {
+    match () {
+        #[cfg(riscv32)]
+        () => super::mstatush::clear_mpelp(),
+        #[cfg(not(riscv32))]
+        () => _clear(1 << 41),
+    }
+}
 
 /// Set U-mode non-instruction-fetch memory endianness
 #[inline]
@@ -343,10 +486,37 @@ pub unsafe fn set_mpp(mpp: MPP) {
 /// Floating-point extension state
 #[inline]
 pub unsafe fn set_fs(fs: FS) {
-    let mut value = _read();
-    value &= !(0x3 << 13); // clear previous value
-    value |= (fs as usize) << 13;
-    _write(value);
+    _write(Mstatus::from(_read()).with_fs(fs).bits());
+}
+
+/// Marks the floating-point state as clean (e.g., after saving it on a context switch)
+#[inline]
+pub unsafe fn mark_fp_clean() {
+    set_fs(FS::Clean);
+}
+
+/// Marks the floating-point state as initial (e.g., after resetting it for a new context)
+#[inline]
+pub unsafe fn mark_fp_initial() {
+    set_fs(FS::Initial);
+}
+
+/// Vector extension state
+#[inline]
+pub unsafe fn set_vs(vs: FS) {
+    _write(Mstatus::from(_read()).with_vs(vs).bits());
+}
+
+/// Marks the vector state as clean (e.g., after saving it on a context switch)
+#[inline]
+pub unsafe fn mark_vector_clean() {
+    set_vs(FS::Clean);
+}
+
+/// Marks the vector state as initial (e.g., after resetting it for a new context)
+#[inline]
+pub unsafe fn mark_vector_initial() {
+    set_vs(FS::Initial);
 }
 
 /// Set S-mode non-instruction-fetch memory endianness
@@ -384,3 +554,93 @@ pub unsafe fn set_mbe(endianness: Endianness) {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_double_trap() {
+        let mstatus = Mstatus::from(0);
+        assert!(!mstatus.sdt());
+        assert!(!mstatus.mdt());
+
+        let mstatus = Mstatus::from(1 << 24);
+        assert!(mstatus.sdt());
+        assert!(!mstatus.mdt());
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let mstatus = Mstatus::from(1 << 42);
+            assert!(!mstatus.sdt());
+            assert!(mstatus.mdt());
+        }
+    }
+
+    #[test]
+    fn test_landing_pad() {
+        let mstatus = Mstatus::from(0);
+        assert!(!mstatus.spelp());
+        assert!(!mstatus.mpelp());
+
+        let mstatus = Mstatus::from(1 << 23);
+        assert!(mstatus.spelp());
+        assert!(!mstatus.mpelp());
+        assert!(!mstatus.sdt());
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let mstatus = Mstatus::from(1 << 41);
+            assert!(!mstatus.spelp());
+            assert!(mstatus.mpelp());
+            assert!(!mstatus.mdt());
+        }
+    }
+
+    #[test]
+    fn test_fs_vs_transitions() {
+        // both units start off
+        let mstatus = Mstatus::from(0);
+        assert_eq!(mstatus.fs(), FS::Off);
+        assert_eq!(mstatus.vs(), FS::Off);
+        assert!(!mstatus.fp_dirty());
+        assert!(!mstatus.vector_dirty());
+
+        // Off -> Initial: the context is given fresh FP state
+        let mstatus = mstatus.with_fs(FS::Initial);
+        assert_eq!(mstatus.fs(), FS::Initial);
+        assert_eq!(mstatus.vs(), FS::Off);
+
+        // Initial -> Dirty: the FP unit is used (set by hardware)
+        let mstatus = mstatus.with_fs(FS::Dirty);
+        assert!(mstatus.fp_dirty());
+        assert!(!mstatus.vector_dirty());
+
+        // Dirty -> Clean: the FP state is saved
+        let mstatus = mstatus.with_fs(FS::Clean);
+        assert_eq!(mstatus.fs(), FS::Clean);
+        assert!(!mstatus.fp_dirty());
+
+        // Clean -> Initial: the state is reset for a new context
+        let mstatus = mstatus.with_fs(FS::Initial);
+        assert_eq!(mstatus.fs(), FS::Initial);
+
+        // Initial -> Off: the FP unit is disabled
+        let mstatus = mstatus.with_fs(FS::Off);
+        assert_eq!(mstatus.fs(), FS::Off);
+
+        // the same state machine applies to the vector unit, without touching FS
+        let mstatus = Mstatus::from(0b10 << 13);
+        for (vs, dirty) in [
+            (FS::Initial, false),
+            (FS::Dirty, true),
+            (FS::Clean, false),
+            (FS::Off, false),
+        ] {
+            let mstatus = mstatus.with_vs(vs);
+            assert_eq!(mstatus.vs(), vs);
+            assert_eq!(mstatus.vector_dirty(), dirty);
+            assert_eq!(mstatus.fs(), FS::Clean);
+        }
+    }
+}
@@ -1,4 +1,8 @@
 //! mstatush register (RISCV-32 only)
+//!
+//! It holds the upper fields of `mstatus` in RISCV-32. The getters and setters of
+//! [`mstatus`](super::mstatus) for these fields delegate to this register in RISCV-32.
+//! There is no `sstatush` register: the S-mode view of these fields is not accessible from S-mode.
 
 pub use super::mstatus::Endianness;
 
@@ -8,7 +12,20 @@ pub struct Mstatush {
     bits: usize,
 }
 
+impl From<usize> for Mstatush {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Mstatush {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
     /// S-mode non-instruction-fetch memory endianness
     #[inline]
     pub fn sbe(&self) -> Endianness {
@@ -20,6 +37,18 @@ impl Mstatush {
     pub fn mbe(&self) -> Endianness {
         Endianness::from(self.bits & (1 << 5) != 0)
     }
+
+    /// M-mode Disable Trap (Smdbltrp extension)
+    #[inline]
+    pub fn mdt(&self) -> bool {
+        self.bits & (1 << 10) != 0
+    }
+
+    /// M-mode Previous Expected Landing Pad state (Zicfilp extension)
+    #[inline]
+    pub fn mpelp(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
 }
 
 read_csr_as_rv32!(Mstatush, 0x310);
@@ -27,6 +56,13 @@ write_csr_rv32!(0x310);
 set_rv32!(0x310);
 clear_rv32!(0x310);
 
+set_clear_csr!(
+    /// M-mode Disable Trap
+    , set_mdt, clear_mdt, 1 << 10);
+set_clear_csr!(
+    /// M-mode Previous Expected Landing Pad state
+    , set_mpelp, clear_mpelp, 1 << 9);
+
 /// Set S-mode non-instruction-fetch memory endianness
 #[inline]
 pub unsafe fn set_sbe(endianness: Endianness) {
@@ -44,3 +80,48 @@ pub unsafe fn set_mbe(endianness: Endianness) {
         Endianness::LittleEndian => _clear(1 << 5),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endianness() {
+        let mut bits = 0;
+        let mstatush = Mstatush::from(bits);
+        assert_eq!(mstatush.sbe(), Endianness::LittleEndian);
+        assert_eq!(mstatush.mbe(), Endianness::LittleEndian);
+
+        bits |= 1 << 4;
+        let mstatush = Mstatush::from(bits);
+        assert_eq!(mstatush.sbe(), Endianness::BigEndian);
+        assert_eq!(mstatush.mbe(), Endianness::LittleEndian);
+
+        bits |= 1 << 5;
+        let mstatush = Mstatush::from(bits);
+        assert_eq!(mstatush.sbe(), Endianness::BigEndian);
+        assert_eq!(mstatush.mbe(), Endianness::BigEndian);
+
+        bits &= !(1 << 4);
+        let mstatush = Mstatush::from(bits);
+        assert_eq!(mstatush.sbe(), Endianness::LittleEndian);
+        assert_eq!(mstatush.mbe(), Endianness::BigEndian);
+        assert!(!mstatush.mdt());
+        assert!(!mstatush.mpelp());
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_mstatus_parity() {
+        use super::super::mstatus::Mstatus;
+
+        // in RISCV-64, the mstatush fields are the upper 32 bits of mstatus
+        for bits in [0, 1 << 4, 1 << 5, 1 << 9, 1 << 10, 0b11 << 4] {
+            let (mstatush, mstatus) = (Mstatush::from(bits), Mstatus::from(bits << 32));
+            assert_eq!(mstatush.sbe(), mstatus.sbe());
+            assert_eq!(mstatush.mbe(), mstatus.mbe());
+            assert_eq!(mstatush.mdt(), mstatus.mdt());
+            assert_eq!(mstatush.mpelp(), mstatus.mpelp());
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! mtinst register (Hypervisor extension)
+//!
+//! On a trap into M-mode, `mtinst` may hold a transformed version of the trapping instruction,
+//! a pseudoinstruction for implicit memory accesses of VS-stage address translation, or zero.
+
+/// Pseudoinstructions written to `mtinst` for guest-page faults during VS-stage address translation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pseudoinstruction {
+    /// 32-bit read for VS-stage address translation
+    Read32 = 0x2000,
+    /// 32-bit write for VS-stage address translation
+    Write32 = 0x2020,
+    /// 64-bit read for VS-stage address translation
+    Read64 = 0x3000,
+    /// 64-bit write for VS-stage address translation
+    Write64 = 0x3020,
+}
+
+/// mtinst register
+#[derive(Clone, Copy, Debug)]
+pub struct Mtinst {
+    bits: usize,
+}
+
+impl From<usize> for Mtinst {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mtinst {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns `true` if the register holds a transformed standard instruction (bit 0 set).
+    #[inline]
+    pub fn is_transformed(&self) -> bool {
+        self.bits & 1 != 0
+    }
+
+    /// Returns `true` if the register holds a transformed instruction and
+    /// the trapping instruction was a 16-bit compressed instruction (bit 1 clear).
+    #[inline]
+    pub fn is_compressed(&self) -> bool {
+        self.is_transformed() && self.bits & (1 << 1) == 0
+    }
+
+    /// Returns the pseudoinstruction held in the register, if any.
+    #[inline]
+    pub fn pseudoinstruction(&self) -> Option<Pseudoinstruction> {
+        match self.bits {
+            0x2000 => Some(Pseudoinstruction::Read32),
+            0x2020 => Some(Pseudoinstruction::Write32),
+            0x3000 => Some(Pseudoinstruction::Read64),
+            0x3020 => Some(Pseudoinstruction::Write64),
+            _ => None,
+        }
+    }
+}
+
+read_csr_as!(Mtinst, 0x34A);
+write_csr_as_usize!(0x34A);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mtinst() {
+        // no information
+        let mtinst = Mtinst::from(0);
+        assert!(!mtinst.is_transformed());
+        assert!(!mtinst.is_compressed());
+        assert_eq!(mtinst.pseudoinstruction(), None);
+
+        // transformed `lw a0` (the address fields are cleared)
+        let mtinst = Mtinst::from(0x0000_2503);
+        assert_eq!(mtinst.bits(), 0x0000_2503);
+        assert!(mtinst.is_transformed());
+        assert!(!mtinst.is_compressed());
+        assert_eq!(mtinst.pseudoinstruction(), None);
+
+        // transformed `c.lw`, with bit 1 cleared
+        let mtinst = Mtinst::from(0x0000_2501);
+        assert!(mtinst.is_transformed());
+        assert!(mtinst.is_compressed());
+
+        for (bits, pseudo) in [
+            (0x2000, Pseudoinstruction::Read32),
+            (0x2020, Pseudoinstruction::Write32),
+            (0x3000, Pseudoinstruction::Read64),
+            (0x3020, Pseudoinstruction::Write64),
+        ] {
+            let mtinst = Mtinst::from(bits);
+            assert_eq!(mtinst.bits(), bits);
+            assert_eq!(pseudo as usize, bits);
+            assert!(!mtinst.is_transformed());
+            assert_eq!(mtinst.pseudoinstruction(), Some(pseudo));
+        }
+    }
+}
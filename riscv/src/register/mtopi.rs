@@ -0,0 +1,73 @@
+//! mtopi register (AIA extension)
+
+/// mtopi register
+///
+/// It reports the highest-priority interrupt that is pending and enabled for Machine mode.
+#[derive(Clone, Copy, Debug)]
+pub struct Mtopi {
+    bits: usize,
+}
+
+impl From<usize> for Mtopi {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mtopi {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Interrupt identity (bits 16-27)
+    ///
+    /// It is the major identity number of the interrupt (i.e., its `mcause` code).
+    /// A value of 0 means that no interrupt is pending and enabled.
+    #[inline]
+    pub fn iid(&self) -> usize {
+        (self.bits >> 16) & 0xFFF
+    }
+
+    /// Interrupt priority (bits 0-7)
+    ///
+    /// Lower values denote higher priorities.
+    #[inline]
+    pub fn iprio(&self) -> usize {
+        self.bits & 0xFF
+    }
+
+    /// Returns `true` if an interrupt is pending and enabled
+    #[inline]
+    pub fn is_interrupting(&self) -> bool {
+        self.iid() != 0
+    }
+}
+
+read_csr_as!(Mtopi, 0xFB0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mtopi() {
+        let mtopi = Mtopi::from(0);
+        assert!(!mtopi.is_interrupting());
+        assert_eq!(mtopi.iid(), 0);
+        assert_eq!(mtopi.iprio(), 0);
+
+        // machine external interrupt (11) with priority 5
+        let mtopi = Mtopi::from((11 << 16) | 5);
+        assert!(mtopi.is_interrupting());
+        assert_eq!(mtopi.iid(), 11);
+        assert_eq!(mtopi.iprio(), 5);
+
+        // reserved bits are ignored
+        let mtopi = Mtopi::from((0xF << 28) | (0xFFF << 16) | (0xFF << 8) | 0xFF);
+        assert_eq!(mtopi.iid(), 0xFFF);
+        assert_eq!(mtopi.iprio(), 0xFF);
+    }
+}
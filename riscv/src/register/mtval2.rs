@@ -0,0 +1,59 @@
+//! mtval2 register (Hypervisor extension)
+//!
+//! On a guest-page fault, `mtval2` holds the guest physical address that faulted, shifted right by 2 bits.
+
+/// mtval2 register
+#[derive(Clone, Copy, Debug)]
+pub struct Mtval2 {
+    bits: usize,
+}
+
+impl From<usize> for Mtval2 {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mtval2 {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the faulting guest physical address of a guest-page fault.
+    ///
+    /// The two least significant bits are zero, as they are not recorded in the register.
+    /// In RISCV-32, guest physical addresses can be 34 bits wide, so the result is a `u64`.
+    #[inline]
+    pub fn guest_physical_address(&self) -> u64 {
+        (self.bits as u64) << 2
+    }
+}
+
+read_csr_as!(Mtval2, 0x34B);
+write_csr_as_usize!(0x34B);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mtval2() {
+        for bits in [0, 1, 0x2000_0400, usize::MAX >> 2] {
+            let mtval2 = Mtval2::from(bits);
+            assert_eq!(mtval2.bits(), bits);
+            assert_eq!(mtval2.guest_physical_address() >> 2, bits as u64);
+        }
+        assert_eq!(
+            Mtval2::from(0x2000_0400).guest_physical_address(),
+            0x8000_1000
+        );
+        // a 34-bit guest physical address (Sv32x4)
+        assert_eq!(
+            Mtval2::from(0xFFFF_FFFF).guest_physical_address(),
+            0x3_FFFF_FFFC
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! mvien register (AIA extension)
+
+/// mvien register
+///
+/// It selects which supervisor-level interrupts are virtual (i.e., not visible in `mip`) for S-mode.
+#[derive(Clone, Copy, Debug)]
+pub struct Mvien {
+    bits: usize,
+}
+
+impl From<usize> for Mvien {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mvien {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor Software Interrupt Enable
+    #[inline]
+    pub fn ssoft(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Local Interrupt Enable (bits 13-XLEN)
+    #[inline]
+    pub fn local(&self, index: usize) -> bool {
+        assert!((13..usize::BITS as usize).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+}
+
+read_csr_as!(Mvien, 0x308);
+write_csr!(0x308);
+set!(0x308);
+clear!(0x308);
+
+set_clear_csr!(
+    /// Supervisor Software Interrupt Enable
+    , set_ssoft, clear_ssoft, 1 << 1);
+
+/// Local Interrupt Enable (bits 13-XLEN)
+#[inline]
+pub unsafe fn set_local(index: usize) {
+    assert!((13..usize::BITS as usize).contains(&index));
+    _set(1 << index);
+}
+
+/// Local Interrupt Enable (bits 13-XLEN)
+#[inline]
+pub unsafe fn clear_local(index: usize) {
+    assert!((13..usize::BITS as usize).contains(&index));
+    _clear(1 << index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mvien() {
+        let mvien = Mvien::from((1 << 1) | (1 << 13));
+        assert!(mvien.ssoft());
+        assert!(mvien.local(13));
+        assert!(!mvien.local(14));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mvien_invalid_local() {
+        Mvien::from(0).local(12);
+    }
+}
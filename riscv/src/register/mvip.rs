@@ -0,0 +1,98 @@
+//! mvip register (AIA extension)
+
+/// mvip register
+///
+/// It allows M-mode to inject supervisor-level interrupts.
+#[derive(Clone, Copy, Debug)]
+pub struct Mvip {
+    bits: usize,
+}
+
+impl From<usize> for Mvip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mvip {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor Software Interrupt Pending
+    #[inline]
+    pub fn ssoft(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Supervisor Timer Interrupt Pending
+    #[inline]
+    pub fn stimer(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Supervisor External Interrupt Pending
+    #[inline]
+    pub fn sext(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+
+    /// Local Interrupt Pending (bits 13-XLEN)
+    #[inline]
+    pub fn local(&self, index: usize) -> bool {
+        assert!((13..usize::BITS as usize).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+}
+
+read_csr_as!(Mvip, 0x309);
+write_csr!(0x309);
+set!(0x309);
+clear!(0x309);
+
+set_clear_csr!(
+    /// Supervisor Software Interrupt Pending
+    , set_ssoft, clear_ssoft, 1 << 1);
+set_clear_csr!(
+    /// Supervisor Timer Interrupt Pending
+    , set_stimer, clear_stimer, 1 << 5);
+set_clear_csr!(
+    /// Supervisor External Interrupt Pending
+    , set_sext, clear_sext, 1 << 9);
+
+/// Local Interrupt Pending (bits 13-XLEN)
+#[inline]
+pub unsafe fn set_local(index: usize) {
+    assert!((13..usize::BITS as usize).contains(&index));
+    _set(1 << index);
+}
+
+/// Local Interrupt Pending (bits 13-XLEN)
+#[inline]
+pub unsafe fn clear_local(index: usize) {
+    assert!((13..usize::BITS as usize).contains(&index));
+    _clear(1 << index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mvip() {
+        let mvip = Mvip::from((1 << 5) | (1 << 20));
+        assert!(!mvip.ssoft());
+        assert!(mvip.stimer());
+        assert!(!mvip.sext());
+        assert!(mvip.local(20));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mvip_invalid_local() {
+        Mvip::from(0).local(12);
+    }
+}
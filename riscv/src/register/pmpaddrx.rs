@@ -26,3 +26,60 @@ reg!(0x3BC, pmpaddr12);
 reg!(0x3BD, pmpaddr13);
 reg!(0x3BE, pmpaddr14);
 reg!(0x3BF, pmpaddr15);
+
+/// Encodes the top of a top-of-range (TOR) region as a `pmpaddr` register value.
+///
+/// The bottom of the region is the address encoded in the previous `pmpaddr` register
+/// (or zero for `pmpaddr0`). `addr` must be 4-byte aligned, as the two least significant bits are dropped.
+#[inline]
+pub const fn tor(addr: usize) -> usize {
+    addr >> 2
+}
+
+/// Encodes a naturally aligned power-of-two (NAPOT) region as a `pmpaddr` register value.
+///
+/// # Panics
+///
+/// If `size` is not a power of two, `size` is smaller than 8 bytes, or `base` is not aligned to `size`.
+/// When evaluated in a `const` context, these conditions are checked at compile time.
+#[inline]
+pub const fn napot_encode(base: usize, size: usize) -> usize {
+    assert!(
+        size.is_power_of_two() && size >= 8,
+        "NAPOT size must be a power of two of at least 8 bytes"
+    );
+    assert!(base & (size - 1) == 0, "NAPOT base must be aligned to size");
+    (base >> 2) | ((size >> 3) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pmpaddr_encoding() {
+        const TABLE: [usize; 4] = [
+            tor(0x2000_0000),
+            napot_encode(0x8000_0000, 8),
+            napot_encode(0x8000_0000, 16),
+            napot_encode(0x8000_0000, 0x1000),
+        ];
+
+        assert_eq!(TABLE[0], 0x0800_0000);
+        assert_eq!(TABLE[1], 0x2000_0000);
+        assert_eq!(TABLE[2], 0x2000_0001);
+        assert_eq!(TABLE[3], 0x2000_01FF);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_napot_encode_size() {
+        napot_encode(0x8000_0000, 0x1800);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_napot_encode_alignment() {
+        napot_encode(0x8000_0800, 0x1000);
+    }
+}
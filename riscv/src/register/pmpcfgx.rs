@@ -22,6 +22,61 @@ pub enum Range {
     NAPOT = 0b11,
 }
 
+/// MmlPermission enum contains all possible access rules for pmp registers when `mseccfg.MML` is set (Smepmp)
+///
+/// With Machine Mode Lockdown, the lock bit no longer only prevents modifications.
+/// Instead, it selects whether the rule applies to M-mode (locked) or S/U-modes (unlocked).
+/// Thus, each variant encodes both the `L` bit and the `R`/`W`/`X` bits of the configuration.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MmlPermission {
+    /// Inaccessible region
+    NONE = 0b000,
+    /// S/U-mode execute-only region
+    UserX = 0b100,
+    /// S/U-mode read-only region
+    UserR = 0b001,
+    /// S/U-mode read/execute region
+    UserRX = 0b101,
+    /// S/U-mode read/write region
+    UserRW = 0b011,
+    /// S/U-mode read/write/execute region
+    UserRWX = 0b111,
+    /// Shared data region: read/write in M-mode, read-only in S/U-modes
+    SharedDataUserR = 0b010,
+    /// Shared data region: read/write in M-mode and S/U-modes
+    SharedDataRW = 0b110,
+    /// Locked inaccessible region
+    LockedNONE = 0x80,
+    /// Locked M-mode execute-only region
+    MachineX = 0x84,
+    /// Locked M-mode read-only region
+    MachineR = 0x81,
+    /// Locked M-mode read/execute region
+    MachineRX = 0x85,
+    /// Locked M-mode read/write region
+    MachineRW = 0x83,
+    /// Locked shared code region: execute-only in M-mode and S/U-modes
+    SharedCodeX = 0x82,
+    /// Locked shared code region: read/execute in M-mode, execute-only in S/U-modes
+    SharedCodeMachineRX = 0x86,
+    /// Locked shared data region: read-only in M-mode and S/U-modes
+    SharedDataR = 0x87,
+}
+
+impl MmlPermission {
+    /// Returns `true` if the rule sets the lock bit
+    #[inline]
+    pub const fn locked(self) -> bool {
+        self as u8 & (1 << 7) != 0
+    }
+
+    /// Returns the pmp configuration byte for this rule and the given range
+    #[inline]
+    pub const fn encode(self, range: Range) -> u8 {
+        self as u8 | (range as u8) << 3
+    }
+}
+
 /// Pmp struct holds a high-level representation of a single pmp configuration
 #[derive(Clone, Copy, Debug)]
 pub struct Pmp {
@@ -81,12 +136,14 @@ impl Pmpcsr {
 /// Physical memory protection configuration
 /// pmpcfg0 struct contains pmp0cfg - pmp3cfg for RV32, and pmp0cfg - pmp7cfg for RV64
 pub mod pmpcfg0 {
-    use super::{Permission, Pmpcsr, Range};
+    use super::{MmlPermission, Permission, Pmpcsr, Range};
 
     read_csr_as!(Pmpcsr, 0x3A0);
     write_csr_as_usize!(0x3A0);
 
     set_pmp!();
+    set_pmp_mml!();
+    lock_pmp!();
     clear_pmp!();
 }
 
@@ -94,24 +151,28 @@ pub mod pmpcfg0 {
 /// pmpcfg1 struct contains pmp4cfg - pmp7cfg for RV32 only
 #[cfg(riscv32)]
 pub mod pmpcfg1 {
-    use super::{Permission, Pmpcsr, Range};
+    use super::{MmlPermission, Permission, Pmpcsr, Range};
 
     read_csr_as!(Pmpcsr, 0x3A1);
     write_csr_as_usize_rv32!(0x3A1);
 
     set_pmp!();
+    set_pmp_mml!();
+    lock_pmp!();
     clear_pmp!();
 }
 
 /// Physical memory protection configuration
 /// pmpcfg2 struct contains pmp8cfg - pmp11cfg for RV32, or pmp8cfg - pmp15cfg for RV64
 pub mod pmpcfg2 {
-    use super::{Permission, Pmpcsr, Range};
+    use super::{MmlPermission, Permission, Pmpcsr, Range};
 
     read_csr_as!(Pmpcsr, 0x3A2);
     write_csr_as_usize!(0x3A2);
 
     set_pmp!();
+    set_pmp_mml!();
+    lock_pmp!();
     clear_pmp!();
 }
 
@@ -119,11 +180,44 @@ pub mod pmpcfg2 {
 /// pmpcfg3 struct contains pmp12cfg - pmp15cfg for RV32 only
 #[cfg(riscv32)]
 pub mod pmpcfg3 {
-    use super::{Permission, Pmpcsr, Range};
+    use super::{MmlPermission, Permission, Pmpcsr, Range};
 
     read_csr_as!(Pmpcsr, 0x3A3);
     write_csr_as_usize_rv32!(0x3A3);
 
     set_pmp!();
+    set_pmp_mml!();
+    lock_pmp!();
     clear_pmp!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mml_permission_encoding() {
+        assert_eq!(MmlPermission::NONE.encode(Range::OFF), 0);
+        assert!(!MmlPermission::UserRX.locked());
+        assert_eq!(MmlPermission::UserRX.encode(Range::NAPOT), 0b0001_1101);
+        assert!(!MmlPermission::SharedDataUserR.locked());
+        assert_eq!(
+            MmlPermission::SharedDataUserR.encode(Range::TOR),
+            0b0000_1010
+        );
+        assert!(MmlPermission::MachineRX.locked());
+        assert_eq!(MmlPermission::MachineRX.encode(Range::NAPOT), 0b1001_1101);
+        assert!(MmlPermission::SharedCodeX.locked());
+        assert_eq!(MmlPermission::SharedCodeX.encode(Range::NA4), 0b1001_0010);
+        assert_eq!(MmlPermission::SharedDataR.encode(Range::TOR), 0b1000_1111);
+
+        // the encoding decodes back to the same L, R, W, X bits
+        let pmp = Pmpcsr {
+            bits: (MmlPermission::MachineRW.encode(Range::TOR) as usize) << 8,
+        }
+        .into_config(1);
+        assert!(pmp.locked);
+        assert!(matches!(pmp.permission, Permission::RW));
+        assert!(matches!(pmp.range, Range::TOR));
+    }
+}
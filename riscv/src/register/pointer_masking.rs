@@ -0,0 +1,95 @@
+//! Effective pointer masking mode (Smmpm, Smnpm, and Ssnpm extensions)
+
+use super::henvcfg::PointerMaskingMode;
+use super::mstatus::MPP;
+use super::{menvcfg, mseccfg, senvcfg};
+
+/// Returns the pointer masking mode of the privilege mode `mode`, consulting the CSR that controls it:
+///
+/// - `MPP::Machine`: `mseccfg.PMM` (Smmpm extension). It must be called from M-mode.
+/// - `MPP::Supervisor`: `menvcfg.PMM` (Smnpm extension). It must be called from M-mode.
+/// - `MPP::User`: `senvcfg.PMM` (Ssnpm extension). It must be called from M-mode or S-mode.
+///
+/// It returns `None` if the CSR holds a reserved encoding.
+///
+/// # Note
+///
+/// `MPP::User` assumes that S-mode is implemented. Otherwise, U-mode is the next-lower privilege
+/// mode of M-mode, and its masking mode is the one returned for `MPP::Supervisor` (i.e., `menvcfg.PMM`).
+/// Pointer masking is only supported in RISCV-64.
+#[inline]
+pub fn pointer_masking(mode: MPP) -> Option<PointerMaskingMode> {
+    pointer_masking_with(mode, mseccfg::read, menvcfg::read, senvcfg::read)
+}
+
+/// Returns the pointer masking mode of the current privilege mode (i.e., M-mode).
+///
+/// Masked pointers can be converted to effective addresses with [`PointerMaskingMode::masked_pointer`].
+///
+/// # Note
+///
+/// This function is not available with the `s-mode` feature, as S-mode cannot read
+/// `menvcfg`, which holds its own pointer masking mode.
+#[cfg(not(feature = "s-mode"))]
+#[inline]
+pub fn current_pointer_masking() -> Option<PointerMaskingMode> {
+    pointer_masking(MPP::Machine)
+}
+
+/// Same as [`pointer_masking`], but using the provided functions to read the CSRs.
+#[inline]
+fn pointer_masking_with<M, N, S>(
+    mode: MPP,
+    read_mseccfg: M,
+    read_menvcfg: N,
+    read_senvcfg: S,
+) -> Option<PointerMaskingMode>
+where
+    M: FnOnce() -> mseccfg::Mseccfg,
+    N: FnOnce() -> menvcfg::Menvcfg,
+    S: FnOnce() -> senvcfg::Senvcfg,
+{
+    match mode {
+        MPP::Machine => read_mseccfg().pmm(),
+        MPP::Supervisor => read_menvcfg().pmm(),
+        MPP::User => read_senvcfg().pmm(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pointer_masking() {
+        let mseccfg = || mseccfg::Mseccfg::from(0b11 << 32);
+        let menvcfg = || menvcfg::Menvcfg::from(0b10 << 32);
+        let senvcfg = || senvcfg::Senvcfg::from(0);
+
+        for (mode, pmm) in [
+            (MPP::Machine, Some(PointerMaskingMode::Bits16)),
+            (MPP::Supervisor, Some(PointerMaskingMode::Bits7)),
+            (MPP::User, Some(PointerMaskingMode::Disabled)),
+        ] {
+            assert_eq!(pointer_masking_with(mode, mseccfg, menvcfg, senvcfg), pmm);
+        }
+
+        // only the CSR of the requested mode is read
+        assert_eq!(
+            pointer_masking_with(
+                MPP::User,
+                || unreachable!(),
+                || unreachable!(),
+                || senvcfg::Senvcfg::from(0b11 << 32)
+            ),
+            Some(PointerMaskingMode::Bits16)
+        );
+
+        // reserved encoding
+        let reserved = || menvcfg::Menvcfg::from(0b01 << 32);
+        assert_eq!(
+            pointer_masking_with(MPP::Supervisor, mseccfg, reserved, senvcfg),
+            None
+        );
+    }
+}
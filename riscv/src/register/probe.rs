@@ -0,0 +1,90 @@
+//! Runtime CSR presence detection
+//!
+//! Reading a CSR that is not implemented raises an illegal instruction exception.
+//! The [`probe_csr!`](crate::probe_csr) macro attempts such a read and reports whether it trapped.
+//! As detecting the trap requires the cooperation of the trap handler, the handler must be set up
+//! **before** probing any CSR:
+//!
+//! 1. On illegal instruction exceptions, the handler calls [`fault`].
+//! 2. If [`fault`] returns `true`, the exception was caused by a probe. The handler must then skip
+//!    the faulting instruction (i.e., add 4 to `mepc`) and return from the trap.
+//!    Otherwise, the handler must process the exception as usual.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use riscv::register::{mcause, mepc, probe};
+//!
+//! // called by the exception handler
+//! fn exception_handler() {
+//!     let cause = mcause::read().cause();
+//!     if cause == mcause::Trap::Exception(mcause::Exception::IllegalInstruction) && probe::fault() {
+//!         mepc::write(mepc::read() + 4); // skip the CSR read (csrr is never compressed)
+//!         return;
+//!     }
+//!     // ...
+//! }
+//!
+//! // during bring-up
+//! let has_stimecmp = riscv::probe_csr!(0x14D);
+//! ```
+//!
+//! # Note
+//!
+//! The probe state is global. Probes must not run concurrently on several harts,
+//! nor be nested (e.g., from interrupt handlers).
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether a probe is in progress.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+/// Whether the probe in progress has trapped.
+static FAULTED: AtomicBool = AtomicBool::new(false);
+
+/// Runs `read` as a CSR probe, and returns `true` if it did not trap.
+///
+/// This is the primitive used by [`probe_csr!`](crate::probe_csr).
+/// `read` must attempt the CSR access. A trap handler set up as described in the
+/// [module documentation](self) marks the probe as failed by calling [`fault`].
+#[inline]
+pub fn probe_csr_with<F: FnOnce()>(read: F) -> bool {
+    FAULTED.store(false, Ordering::SeqCst);
+    ACTIVE.store(true, Ordering::SeqCst);
+    read();
+    ACTIVE.store(false, Ordering::SeqCst);
+    !FAULTED.load(Ordering::SeqCst)
+}
+
+/// Notifies an illegal instruction exception to the probe in progress.
+///
+/// It returns `true` if a probe is in progress. In that case, the exception was caused by the probed
+/// CSR, and the trap handler must skip the faulting instruction. Otherwise, it returns `false`.
+#[inline]
+pub fn fault() -> bool {
+    let active = ACTIVE.load(Ordering::SeqCst);
+    if active {
+        FAULTED.store(true, Ordering::SeqCst);
+    }
+    active
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_csr() {
+        // no probe in progress: the exception must be handled as usual
+        assert!(!fault());
+
+        // implemented CSR: the read does not trap
+        assert!(probe_csr_with(|| {}));
+
+        // unimplemented CSR: the emulated trap handler reports the exception
+        assert!(!probe_csr_with(|| assert!(fault())));
+
+        // the state of a failed probe does not leak into the next one
+        assert!(probe_csr_with(|| {}));
+        assert!(!fault());
+    }
+}
@@ -0,0 +1,75 @@
+//! scontext register
+//!
+//! Supervisor context register of the debug trigger module (Sdtrig).
+//! Triggers can be configured to only match when the `data` field equals a given value.
+
+/// Mask of the `data` field.
+///
+/// The specification recommends implementing 16 bits on RV32 and 32 bits on RV64.
+/// Implementations may hardwire any number of the upper bits to zero.
+#[cfg(target_pointer_width = "32")]
+pub const DATA_MASK: usize = 0xFFFF;
+
+/// Mask of the `data` field.
+///
+/// The specification recommends implementing 16 bits on RV32 and 32 bits on RV64.
+/// Implementations may hardwire any number of the upper bits to zero.
+#[cfg(target_pointer_width = "64")]
+pub const DATA_MASK: usize = 0xFFFF_FFFF;
+
+/// scontext register
+#[derive(Clone, Copy, Debug)]
+pub struct Scontext {
+    bits: usize,
+}
+
+impl From<usize> for Scontext {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Scontext {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor context data (`data` field)
+    #[inline]
+    pub fn data(&self) -> usize {
+        self.bits & DATA_MASK
+    }
+}
+
+read_csr_as!(Scontext, 0x5A8);
+write_csr!(0x5A8);
+
+/// Writes the `data` field of the CSR
+#[inline]
+pub fn write(data: usize) {
+    unsafe { _write(data & DATA_MASK) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scontext() {
+        for bits in [0, 1, 0xBEEF, DATA_MASK] {
+            let scontext = Scontext::from(bits);
+            assert_eq!(scontext.bits(), bits);
+            assert_eq!(scontext.data(), bits);
+        }
+
+        // bits above the data field are ignored
+        let scontext = Scontext::from(usize::MAX);
+        assert_eq!(scontext.bits(), usize::MAX);
+        assert_eq!(scontext.data(), DATA_MASK);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(Scontext::from(DATA_MASK + 1).data(), 0);
+    }
+}
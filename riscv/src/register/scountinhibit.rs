@@ -0,0 +1,90 @@
+//! scountinhibit register (Ssccfg extension)
+
+/// scountinhibit register
+///
+/// It allows S-mode to inhibit the counters delegated to it by M-mode.
+#[derive(Clone, Copy, Debug)]
+pub struct Scountinhibit {
+    bits: usize,
+}
+
+impl From<usize> for Scountinhibit {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Scountinhibit {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// "cycle\[h\]" Inhibit
+    #[inline]
+    pub fn cy(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// "instret\[h\]" Inhibit
+    #[inline]
+    pub fn ir(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// "hpm\[x\]" Inhibit (bits 3-31)
+    #[inline]
+    pub fn hpm(&self, index: usize) -> bool {
+        assert!((3..32).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+}
+
+read_csr_as!(Scountinhibit, 0x120);
+write_csr!(0x120);
+set!(0x120);
+clear!(0x120);
+
+set_clear_csr!(
+    /// "cycle\[h\]" Inhibit
+    , set_cy, clear_cy, 1 << 0);
+
+set_clear_csr!(
+    /// "instret\[h\]" Inhibit
+    , set_ir, clear_ir, 1 << 2);
+
+/// "hpm\[x\]" Inhibit (bits 3-31)
+#[inline]
+pub unsafe fn set_hpm(index: usize) {
+    assert!((3..32).contains(&index));
+    _set(1 << index);
+}
+
+/// "hpm\[x\]" Inhibit (bits 3-31)
+#[inline]
+pub unsafe fn clear_hpm(index: usize) {
+    assert!((3..32).contains(&index));
+    _clear(1 << index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scountinhibit() {
+        let scountinhibit = Scountinhibit::from(0);
+        assert!(!scountinhibit.cy());
+        assert!(!scountinhibit.ir());
+        assert!((3..32).all(|index| !scountinhibit.hpm(index)));
+
+        let scountinhibit = Scountinhibit::from((1 << 0) | (1 << 3) | (1 << 31));
+        assert!(scountinhibit.cy());
+        assert!(!scountinhibit.ir());
+        assert!(scountinhibit.hpm(3));
+        assert!(!scountinhibit.hpm(4));
+        assert!(scountinhibit.hpm(31));
+    }
+}
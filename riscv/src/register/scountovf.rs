@@ -0,0 +1,63 @@
+//! scountovf register (Sscofpmf extension)
+
+/// scountovf register
+#[derive(Clone, Copy, Debug)]
+pub struct Scountovf {
+    bits: usize,
+}
+
+impl From<usize> for Scountovf {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Scountovf {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Overflow status of "hpmcounter\[x\]" (bits 3-31)
+    #[inline]
+    pub fn of(&self, index: usize) -> bool {
+        assert!((3..32).contains(&index));
+        self.bits & (1 << index) != 0
+    }
+
+    /// Returns an iterator over the indices of the overflowed "hpmcounter\[x\]" counters
+    #[inline]
+    pub fn overflowed(&self) -> impl Iterator<Item = usize> {
+        let bits = self.bits;
+        (3..32).filter(move |&index| bits & (1 << index) != 0)
+    }
+}
+
+read_csr_as!(Scountovf, 0xDA0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scountovf() {
+        let scountovf = Scountovf::from(0);
+        assert_eq!(scountovf.overflowed().count(), 0);
+        assert!((3..32).all(|index| !scountovf.of(index)));
+
+        // cycle, time and instret bits are ignored
+        let scountovf = Scountovf::from((1 << 4) | (1 << 31) | 0b111);
+        assert!(scountovf.of(4));
+        assert!(scountovf.of(31));
+        assert!(!scountovf.of(3));
+        assert!(scountovf.overflowed().eq([4, 31]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scountovf_invalid_index() {
+        Scountovf::from(usize::MAX).of(2);
+    }
+}
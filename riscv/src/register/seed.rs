@@ -0,0 +1,195 @@
+//! seed register (Zkr extension)
+//!
+//! Each read of `seed` returns fresh entropy from the entropy source of the HART together with
+//! its operational status. The CSR must be accessed with a read-write instruction: a read-only
+//! access (e.g., `csrrs` with `rs1 = x0`) raises an illegal instruction exception.
+//! Access from S-mode and U-mode is controlled by [`mseccfg`](super::mseccfg)`.SSEED` and `.USEED`.
+
+/// Operational status of the entropy source (`OPST` field)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OPST {
+    /// Built-in self-test in progress. No entropy is available.
+    Bist = 0,
+    /// The entropy source is not ready yet. Try again later.
+    Wait = 1,
+    /// 16 bits of entropy are available.
+    Es16 = 2,
+    /// Unrecoverable self-test error. No entropy will ever be available.
+    Dead = 3,
+}
+
+/// Error returned when no entropy is available
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EntropyError {
+    /// Built-in self-test in progress.
+    Bist,
+    /// The entropy source is not ready yet.
+    Wait,
+    /// Unrecoverable self-test error.
+    Dead,
+}
+
+/// seed register
+#[derive(Clone, Copy, Debug)]
+pub struct Seed {
+    bits: usize,
+}
+
+impl From<usize> for Seed {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Seed {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Operational status of the entropy source (bits 30-31)
+    #[inline]
+    pub fn opst(&self) -> OPST {
+        match (self.bits >> 30) & 0x3 {
+            0 => OPST::Bist,
+            1 => OPST::Wait,
+            2 => OPST::Es16,
+            3 => OPST::Dead,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the 16 bits of entropy (bits 0-15) if the status is [`OPST::Es16`].
+    #[inline]
+    pub fn entropy(&self) -> Result<u16, EntropyError> {
+        match self.opst() {
+            OPST::Bist => Err(EntropyError::Bist),
+            OPST::Wait => Err(EntropyError::Wait),
+            OPST::Es16 => Ok(self.bits as u16),
+            OPST::Dead => Err(EntropyError::Dead),
+        }
+    }
+}
+
+#[cfg(feature = "test-mock")]
+std::thread_local! {
+    /// Programmed sequence of `(status, entropy)` pairs returned by [`read`] in the current thread.
+    static MOCK_ENTROPY: core::cell::Cell<&'static [(OPST, u16)]> = const { core::cell::Cell::new(&[]) };
+}
+
+/// Programs a sequence of `(status, entropy)` pairs to be returned by [`read`] in the current thread.
+/// This allows testing code built on the entropy source on the host.
+///
+/// Each call to [`read`] consumes one pair. Once the sequence is exhausted,
+/// [`read`] accesses the `seed` CSR again.
+#[cfg(feature = "test-mock")]
+#[inline]
+pub fn set_mock_entropy(sequence: &'static [(OPST, u16)]) {
+    MOCK_ENTROPY.with(|mock| mock.set(sequence));
+}
+
+/// Removes the remaining programmed sequence of the current thread.
+#[cfg(feature = "test-mock")]
+#[inline]
+pub fn clear_mock_entropy() {
+    MOCK_ENTROPY.with(|mock| mock.set(&[]));
+}
+
+/// Pops the next programmed `seed` value of the current thread, if any.
+#[cfg(feature = "test-mock")]
+#[inline]
+fn mock_read() -> Option<Seed> {
+    MOCK_ENTROPY.with(|mock| {
+        let (&(opst, entropy), rest) = mock.get().split_first()?;
+        mock.set(rest);
+        Some(Seed::from(((opst as usize) << 30) | entropy as usize))
+    })
+}
+
+/// Reads the CSR
+///
+/// As it uses a read-write access, each call polls the entropy source once.
+///
+/// If the `test-mock` feature is enabled and a sequence has been programmed
+/// with [`set_mock_entropy`], it returns the next value of the sequence instead.
+#[inline]
+pub fn read() -> Seed {
+    #[cfg(feature = "test-mock")]
+    if let Some(seed) = mock_read() {
+        return seed;
+    }
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let r: usize;
+            // SAFETY: writes to `seed` are ignored
+            unsafe { core::arch::asm!("csrrw {0}, 0x015, x0", out(reg) r) };
+            Seed { bits: r }
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// Polls the entropy source once and returns 16 bits of entropy if available.
+#[inline]
+pub fn read_entropy() -> Result<u16, EntropyError> {
+    read().entropy()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed() {
+        for (opst, status, entropy) in [
+            (0b00, OPST::Bist, Err(EntropyError::Bist)),
+            (0b01, OPST::Wait, Err(EntropyError::Wait)),
+            (0b10, OPST::Es16, Ok(0xBEEF)),
+            (0b11, OPST::Dead, Err(EntropyError::Dead)),
+        ] {
+            // reserved and custom bits (16-29) are ignored
+            let seed = Seed::from((opst << 30) | (0xAA << 16) | 0xBEEF);
+            assert_eq!(seed.opst(), status);
+            assert_eq!(seed.entropy(), entropy);
+        }
+        assert_eq!(Seed::from(0b10 << 30).entropy(), Ok(0));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_mock_entropy() {
+        /// Polls the entropy source until it is ready, as an RNG driver would do.
+        fn next_u16() -> Result<u16, EntropyError> {
+            loop {
+                match read_entropy() {
+                    Err(EntropyError::Wait) => continue,
+                    entropy => return entropy,
+                }
+            }
+        }
+
+        set_mock_entropy(&[
+            (OPST::Bist, 0),
+            (OPST::Wait, 0),
+            (OPST::Es16, 0x1234),
+            (OPST::Wait, 0),
+            (OPST::Wait, 0),
+            (OPST::Es16, 0xABCD),
+            (OPST::Dead, 0),
+        ]);
+        assert_eq!(read().opst(), OPST::Bist);
+        assert_eq!(next_u16(), Ok(0x1234));
+        assert_eq!(next_u16(), Ok(0xABCD));
+        assert_eq!(next_u16(), Err(EntropyError::Dead));
+
+        set_mock_entropy(&[(OPST::Es16, 1), (OPST::Es16, 2)]);
+        assert_eq!(read_entropy(), Ok(1));
+        clear_mock_entropy();
+        assert!(mock_read().is_none());
+    }
+}
@@ -0,0 +1,154 @@
+//! senvcfg register
+
+pub use super::henvcfg::{PointerMaskingMode, CBIE};
+
+/// senvcfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Senvcfg {
+    bits: usize,
+}
+
+impl From<usize> for Senvcfg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Senvcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Fence of I/O implies Memory
+    #[inline]
+    pub fn fiom(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// Landing Pad Enable (Zicfilp extension)
+    #[inline]
+    pub fn lpe(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Cache Block Invalidate instruction Enable (Zicbom extension)
+    #[inline]
+    pub fn cbie(&self) -> Option<CBIE> {
+        match (self.bits >> 4) & 0x3 {
+            0b00 => Some(CBIE::IllegalInstruction),
+            0b01 => Some(CBIE::Flush),
+            0b11 => Some(CBIE::Invalidate),
+            _ => None,
+        }
+    }
+
+    /// Cache Block Clean and Flush instruction Enable (Zicbom extension)
+    #[inline]
+    pub fn cbcfe(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Cache Block Zero instruction Enable (Zicboz extension)
+    #[inline]
+    pub fn cbze(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+
+    /// Pointer Masking Mode for U-mode (Ssnpm extension)
+    ///
+    /// Pointer masking is only supported in RISCV-64.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn pmm(&self) -> Option<PointerMaskingMode> {
+        match (self.bits >> 32) & 0x3 {
+            0b00 => Some(PointerMaskingMode::Disabled),
+            0b10 => Some(PointerMaskingMode::Bits7),
+            0b11 => Some(PointerMaskingMode::Bits16),
+            _ => None,
+        }
+    }
+}
+
+read_csr_as!(Senvcfg, 0x10A);
+write_csr!(0x10A);
+set!(0x10A);
+clear!(0x10A);
+
+set_clear_csr!(
+    /// Fence of I/O implies Memory
+    , set_fiom, clear_fiom, 1 << 0);
+set_clear_csr!(
+    /// Landing Pad Enable
+    , set_lpe, clear_lpe, 1 << 2);
+set_clear_csr!(
+    /// Cache Block Clean and Flush instruction Enable
+    , set_cbcfe, clear_cbcfe, 1 << 6);
+set_clear_csr!(
+    /// Cache Block Zero instruction Enable
+    , set_cbze, clear_cbze, 1 << 7);
+
+/// Cache Block Invalidate instruction Enable
+#[inline]
+pub unsafe fn set_cbie(cbie: CBIE) {
+    let mut value = _read();
+    value &= !(0x3 << 4); // clear previous value
+    value |= (cbie as usize) << 4;
+    _write(value);
+}
+
+/// Pointer Masking Mode
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn set_pmm(pmm: PointerMaskingMode) {
+    let mut value = _read();
+    value &= !(0x3 << 32); // clear previous value
+    value |= (pmm as usize) << 32;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_senvcfg() {
+        let senvcfg = Senvcfg::from(0);
+        assert!(!senvcfg.fiom());
+        assert_eq!(senvcfg.cbie(), Some(CBIE::IllegalInstruction));
+        assert!(!senvcfg.cbcfe());
+        assert!(!senvcfg.cbze());
+
+        let senvcfg = Senvcfg::from((1 << 0) | (0b01 << 4) | (1 << 6));
+        assert!(senvcfg.fiom());
+        assert_eq!(senvcfg.cbie(), Some(CBIE::Flush));
+        assert!(senvcfg.cbcfe());
+        assert!(!senvcfg.cbze());
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(senvcfg.pmm(), Some(PointerMaskingMode::Disabled));
+            assert_eq!(
+                Senvcfg::from(0b10 << 32).pmm(),
+                Some(PointerMaskingMode::Bits7)
+            );
+            assert_eq!(
+                Senvcfg::from(0b11 << 32).pmm(),
+                Some(PointerMaskingMode::Bits16)
+            );
+            assert_eq!(Senvcfg::from(0b01 << 32).pmm(), None);
+        }
+
+        // Zicfilp landing pads
+        let mut bits = 0b11 << 4;
+        assert!(!Senvcfg::from(bits).lpe());
+        bits |= 1 << 2;
+        let senvcfg = Senvcfg::from(bits);
+        assert!(senvcfg.lpe());
+        assert_eq!(senvcfg.cbie(), Some(CBIE::Invalidate));
+        bits &= !(1 << 2);
+        assert!(!Senvcfg::from(bits).lpe());
+    }
+}
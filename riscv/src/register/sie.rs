@@ -1,11 +1,20 @@
 //! sie register
 
+pub use super::{InterruptEnableCsr, InterruptFlags};
+
 /// sie register
 #[derive(Clone, Copy, Debug)]
 pub struct Sie {
     bits: usize,
 }
 
+impl From<usize> for Sie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Sie {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -13,6 +22,12 @@ impl Sie {
         self.bits
     }
 
+    /// Returns the enabled standard interrupts as a set of flags
+    #[inline]
+    pub fn flags(&self) -> InterruptFlags {
+        InterruptFlags::from_bits_truncate(self.bits)
+    }
+
     /// Supervisor Software Interrupt Enable
     #[inline]
     pub fn ssoft(&self) -> bool {
@@ -32,10 +47,42 @@ impl Sie {
     }
 }
 
+impl InterruptEnableCsr for Sie {
+    const SOFT: InterruptFlags = InterruptFlags::SSOFT;
+    const TIMER: InterruptFlags = InterruptFlags::STIMER;
+    const EXT: InterruptFlags = InterruptFlags::SEXT;
+
+    #[inline]
+    fn read() -> Self {
+        read()
+    }
+
+    #[inline]
+    fn flags(&self) -> InterruptFlags {
+        Sie::flags(self)
+    }
+}
+
 read_csr_as!(Sie, 0x104);
 set!(0x104);
 clear!(0x104);
 
+/// Enables all the interrupts in `flags` with a single CSR write.
+///
+/// Only supervisor-level flags are writable in `sie`.
+#[inline]
+pub unsafe fn enable(flags: InterruptFlags) {
+    _set(flags.bits());
+}
+
+/// Disables all the interrupts in `flags` with a single CSR write.
+///
+/// Only supervisor-level flags are writable in `sie`.
+#[inline]
+pub unsafe fn disable(flags: InterruptFlags) {
+    _clear(flags.bits());
+}
+
 set_clear_csr!(
     /// Supervisor Software Interrupt Enable
     , set_ssoft, clear_ssoft, 1 << 1);
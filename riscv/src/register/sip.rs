@@ -1,4 +1,22 @@
 //! sip register
+//!
+//! # Supervisor timer interrupt
+//!
+//! `sip.STIP` is read-only for S-mode. Without the Sstc extension, it is set and cleared by M-mode
+//! through `mip` (usually, on behalf of an SBI `set_timer` call), so S-mode cannot clear it directly.
+//! With the Sstc extension (`sstc` feature) and `menvcfg.STCE` set, `STIP` reflects whether `time`
+//! has reached [`stimecmp`](super::stimecmp). Then, S-mode clears it with [`clear_stimer`],
+//! which programs `stimecmp` instead of writing `sip`.
+#![cfg_attr(
+    not(feature = "sstc"),
+    doc = r#"
+Without the `sstc` feature, there is no way to clear `STIP` from S-mode:
+
+```compile_fail
+unsafe { riscv::register::sip::clear_stimer() };
+```
+"#
+)]
 
 /// sip register
 #[derive(Clone, Copy, Debug)]
@@ -39,3 +57,52 @@ clear!(0x144);
 set_clear_csr!(
     /// Supervisor Software Interrupt Pending
     , set_ssoft, clear_ssoft, 1 << 1);
+
+/// Supervisor Timer Interrupt Pending
+///
+/// It clears `STIP` by setting [`stimecmp`](super::stimecmp) to `u64::MAX`,
+/// so that the timer interrupt does not become pending again.
+///
+/// # Note
+///
+/// It only has an effect if `menvcfg.STCE` is set. Otherwise, `stimecmp` does not drive `STIP`.
+#[cfg(feature = "sstc")]
+#[inline]
+pub unsafe fn clear_stimer() {
+    clear_stimer_with(|value| super::stimecmp::write64(value));
+}
+
+/// Same as [`clear_stimer`], but using the provided function to write `stimecmp`.
+#[cfg(feature = "sstc")]
+#[inline]
+fn clear_stimer_with<W: FnOnce(u64)>(write_stimecmp: W) {
+    write_stimecmp(u64::MAX);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sip() {
+        let sip = Sip {
+            bits: (1 << 1) | (1 << 5),
+        };
+        assert!(sip.ssoft());
+        assert!(sip.stimer());
+        assert!(!sip.sext());
+
+        let sip = Sip { bits: 1 << 9 };
+        assert!(!sip.ssoft());
+        assert!(!sip.stimer());
+        assert!(sip.sext());
+    }
+
+    #[cfg(feature = "sstc")]
+    #[test]
+    fn test_clear_stimer() {
+        let stimecmp = core::cell::Cell::new(None);
+        clear_stimer_with(|value| stimecmp.set(Some(value)));
+        assert_eq!(stimecmp.get(), Some(u64::MAX));
+    }
+}
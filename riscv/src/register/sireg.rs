@@ -0,0 +1,100 @@
+//! sireg register (Sscsrind extension)
+//!
+//! It gives access to the indirect register selected by [`crate::register::siselect`].
+
+read_csr_as_usize!(0x151);
+write_csr_as_usize!(0x151);
+
+/// Reads the indirect register selected by `select`.
+///
+/// It writes `select` to the `siselect` CSR and then reads the `sireg` CSR.
+///
+/// # Safety
+///
+/// The sequence is not atomic. An interrupt handler that changes `siselect`
+/// between both accesses makes this function read a different indirect register.
+/// Disable interrupts or make sure that handlers restore `siselect` before returning.
+#[inline]
+pub unsafe fn read_indirect(select: usize) -> usize {
+    read_indirect_with(select, super::siselect::write, read)
+}
+
+/// Writes `value` to the indirect register selected by `select`.
+///
+/// It writes `select` to the `siselect` CSR and then writes `value` to the `sireg` CSR.
+///
+/// # Safety
+///
+/// The sequence is not atomic. An interrupt handler that changes `siselect`
+/// between both accesses makes this function write a different indirect register.
+/// Disable interrupts or make sure that handlers restore `siselect` before returning.
+#[inline]
+pub unsafe fn write_indirect(select: usize, value: usize) {
+    write_indirect_with(select, value, super::siselect::write, write);
+}
+
+/// Same as [`read_indirect`], but using the provided functions to access the CSRs.
+#[inline]
+fn read_indirect_with<S: FnOnce(usize), R: FnOnce() -> usize>(
+    select: usize,
+    write_select: S,
+    read_reg: R,
+) -> usize {
+    write_select(select);
+    read_reg()
+}
+
+/// Same as [`write_indirect`], but using the provided functions to access the CSRs.
+#[inline]
+fn write_indirect_with<S: FnOnce(usize), W: FnOnce(usize)>(
+    select: usize,
+    value: usize,
+    write_select: S,
+    write_reg: W,
+) {
+    write_select(select);
+    write_reg(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_read_indirect() {
+        // `None` until siselect is written, and then the selected register
+        let selected = Cell::new(None);
+        let value = read_indirect_with(
+            0x30,
+            |s| {
+                assert_eq!(selected.get(), None);
+                selected.set(Some(s));
+            },
+            || {
+                assert_eq!(selected.get(), Some(0x30), "sireg accessed before siselect");
+                0xAA
+            },
+        );
+        assert_eq!(value, 0xAA);
+    }
+
+    #[test]
+    fn test_write_indirect() {
+        let selected = Cell::new(None);
+        let written = Cell::new(None);
+        write_indirect_with(
+            0x30,
+            0x55,
+            |s| {
+                assert_eq!((selected.get(), written.get()), (None, None));
+                selected.set(Some(s));
+            },
+            |v| {
+                assert_eq!(selected.get(), Some(0x30), "sireg accessed before siselect");
+                written.set(Some(v));
+            },
+        );
+        assert_eq!(written.get(), Some(0x55));
+    }
+}
@@ -0,0 +1,4 @@
+//! siselect register (Sscsrind extension)
+
+read_csr_as_usize!(0x150);
+write_csr_as_usize!(0x150);
@@ -0,0 +1,82 @@
+//! srmcfg register (Ssqosid extension)
+
+/// srmcfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Srmcfg {
+    bits: usize,
+}
+
+impl From<usize> for Srmcfg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Srmcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Resource Control ID (bits 0-11)
+    #[inline]
+    pub fn rcid(&self) -> usize {
+        self.bits & 0xFFF
+    }
+
+    /// Monitoring Counter ID (bits 16-27)
+    #[inline]
+    pub fn mcid(&self) -> usize {
+        (self.bits >> 16) & 0xFFF
+    }
+}
+
+read_csr_as!(Srmcfg, 0x181);
+write_csr!(0x181);
+
+/// Resource Control ID
+///
+/// Only the lowest 12 bits of `rcid` are used.
+#[inline]
+pub unsafe fn set_rcid(rcid: usize) {
+    let mut value = _read();
+    value &= !0xFFF; // clear previous value
+    value |= rcid & 0xFFF;
+    _write(value);
+}
+
+/// Monitoring Counter ID
+///
+/// Only the lowest 12 bits of `mcid` are used.
+#[inline]
+pub unsafe fn set_mcid(mcid: usize) {
+    let mut value = _read();
+    value &= !(0xFFF << 16); // clear previous value
+    value |= (mcid & 0xFFF) << 16;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srmcfg() {
+        let srmcfg = Srmcfg::from(0);
+        assert_eq!(srmcfg.rcid(), 0);
+        assert_eq!(srmcfg.mcid(), 0);
+
+        for (rcid, mcid) in [(1, 0), (0, 1), (0xABC, 0x123), (0xFFF, 0xFFF)] {
+            let srmcfg = Srmcfg::from((mcid << 16) | rcid);
+            assert_eq!(srmcfg.rcid(), rcid);
+            assert_eq!(srmcfg.mcid(), mcid);
+        }
+
+        // reserved bits are ignored
+        let srmcfg = Srmcfg::from(0xF000_F000 | (0x5 << 16) | 0xA);
+        assert_eq!(srmcfg.rcid(), 0xA);
+        assert_eq!(srmcfg.mcid(), 0x5);
+    }
+}
@@ -0,0 +1,18 @@
+//! ssp register (Zicfiss extension)
+//!
+//! It holds the shadow stack pointer of the current privilege mode.
+
+read_csr_as_usize!(0x011);
+write_csr!(0x011);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// Moving the shadow stack pointer makes subsequent `SSPOPCHK` instructions compare return
+/// addresses against a different shadow stack. It must only be used to switch between
+/// valid shadow stacks (e.g., on context switches).
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
@@ -0,0 +1,68 @@
+//! sstateen0 register (Smstateen extension)
+
+/// sstateen0 register
+#[derive(Clone, Copy, Debug)]
+pub struct Sstateen0 {
+    bits: usize,
+}
+
+impl From<usize> for Sstateen0 {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Sstateen0 {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Custom state enable
+    #[inline]
+    pub fn c(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// `fcsr` access enable (for harts with `misa.F` = 0, e.g. Zfinx)
+    #[inline]
+    pub fn fcsr(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// `jvt` access enable (Zcmt extension)
+    #[inline]
+    pub fn jvt(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+}
+
+read_csr_as!(Sstateen0, 0x10C);
+write_csr!(0x10C);
+set!(0x10C);
+clear!(0x10C);
+
+set_clear_csr!(
+    /// Custom state enable
+    , set_c, clear_c, 1 << 0);
+set_clear_csr!(
+    /// `fcsr` access enable
+    , set_fcsr, clear_fcsr, 1 << 1);
+set_clear_csr!(
+    /// `jvt` access enable
+    , set_jvt, clear_jvt, 1 << 2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sstateen0() {
+        let sstateen0 = Sstateen0::from(0b110);
+        assert!(!sstateen0.c());
+        assert!(sstateen0.fcsr());
+        assert!(sstateen0.jvt());
+    }
+}
@@ -0,0 +1,15 @@
+macro_rules! reg {
+    (
+        $addr:expr, $csr:ident
+    ) => {
+        /// Supervisor state enable register (Smstateen extension)
+        pub mod $csr {
+            read_csr_as_usize!($addr);
+            write_csr_as_usize!($addr);
+        }
+    };
+}
+
+reg!(0x10D, sstateen1);
+reg!(0x10E, sstateen2);
+reg!(0x10F, sstateen3);
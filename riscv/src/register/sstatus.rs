@@ -16,7 +16,20 @@ pub enum SPP {
     User = 0,
 }
 
+impl From<usize> for Sstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Sstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
     /// Supervisor Interrupt Enable
     #[inline]
     pub fn sie(&self) -> bool {
@@ -38,6 +51,19 @@ impl Sstatus {
         }
     }
 
+    /// The status of the vector unit
+    #[inline]
+    pub fn vs(&self) -> FS {
+        let vs = (self.bits >> 9) & 0x3; // bits 9-10
+        match vs {
+            0 => FS::Off,
+            1 => FS::Initial,
+            2 => FS::Clean,
+            3 => FS::Dirty,
+            _ => unreachable!(),
+        }
+    }
+
     /// The status of the floating-point unit
     #[inline]
     pub fn fs(&self) -> FS {
@@ -90,6 +116,18 @@ impl Sstatus {
         }
     }
 
+    /// S-mode Previous Expected Landing Pad state (Zicfilp extension)
+    #[inline]
+    pub fn spelp(&self) -> bool {
+        self.bits & (1 << 23) != 0
+    }
+
+    /// S-mode Disable Trap (Ssdbltrp extension)
+    #[inline]
+    pub fn sdt(&self) -> bool {
+        self.bits & (1 << 24) != 0
+    }
+
     /// Whether either the FS field or XS field
     /// signals the presence of some dirty state
     #[inline]
@@ -121,6 +159,12 @@ set_clear_csr!(
 set_clear_csr!(
     /// Make eXecutable Readable
     , set_mxr, clear_mxr, 1 << 19);
+set_clear_csr!(
+    /// S-mode Previous Expected Landing Pad state
+    , set_spelp, clear_spelp, 1 << 23);
+set_clear_csr!(
+    /// S-mode Disable Trap
+    , set_sdt, clear_sdt, 1 << 24);
 
 /// Supervisor Previous Privilege Mode
 #[inline]
@@ -139,3 +183,12 @@ pub unsafe fn set_fs(fs: FS) {
     value |= (fs as usize) << 13;
     _write(value);
 }
+
+/// The status of the vector unit
+#[inline]
+pub unsafe fn set_vs(vs: FS) {
+    let mut value = _read();
+    value &= !(0x3 << 9); // clear previous value
+    value |= (vs as usize) << 9;
+    _write(value);
+}
@@ -0,0 +1,71 @@
+//! stimecmp register (Sstc extension)
+//!
+//! When `menvcfg.STCE` is set, `sip.STIP` is pending whenever `time` is greater than or equal to `stimecmp`.
+
+read_csr_as_usize!(0x14D);
+read_composite_csr!(super::stimecmph::read(), read());
+write_csr!(0x14D);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// It changes when the supervisor timer interrupt becomes pending.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
+
+/// Writes the CSR as a 64-bit value
+///
+/// In RISCV-32, the lower half is set to all ones before writing the upper half,
+/// so the comparator never holds an intermediate value lower than both the old and the new ones.
+/// Thus, no spurious timer interrupt is raised during the update.
+///
+/// # Safety
+///
+/// It changes when the supervisor timer interrupt becomes pending.
+#[inline]
+pub unsafe fn write64(value: u64) {
+    match () {
+        #[cfg(riscv32)]
+        () => write64_with(value, |lo| _write(lo), |hi| super::stimecmph::write(hi)),
+
+        #[cfg(not(riscv32))]
+        () => _write(value as usize),
+    }
+}
+
+/// Writes a 64-bit comparator split in two 32-bit CSRs without spurious matches.
+#[cfg(any(riscv32, test))]
+#[inline]
+fn write64_with<L: FnMut(usize), H: FnMut(usize)>(value: u64, mut write_lo: L, mut write_hi: H) {
+    write_lo(u32::MAX as usize);
+    write_hi((value >> 32) as usize);
+    write_lo(value as u32 as usize);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_write64() {
+        let stimecmp = Cell::new(0x0000_0001_FFFF_FFF0u64);
+        let min = Cell::new(u64::MAX);
+        let record = |value: u64| {
+            stimecmp.set(value);
+            min.set(min.get().min(value));
+        };
+
+        write64_with(
+            0x0000_0002_0000_0010,
+            |lo| record((stimecmp.get() & !0xFFFF_FFFF) | lo as u64),
+            |hi| record((stimecmp.get() & 0xFFFF_FFFF) | (hi as u64) << 32),
+        );
+        assert_eq!(stimecmp.get(), 0x0000_0002_0000_0010);
+        // the comparator never went below its old value
+        assert!(min.get() >= 0x0000_0001_FFFF_FFF0);
+    }
+}
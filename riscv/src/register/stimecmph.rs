@@ -0,0 +1,16 @@
+//! stimecmph register (Sstc extension)
+//!
+//! Upper 32 bits of [`stimecmp`](super::stimecmp) in RISCV-32.
+
+read_csr_as_usize_rv32!(0x15D);
+write_csr_rv32!(0x15D);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// It changes when the supervisor timer interrupt becomes pending.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
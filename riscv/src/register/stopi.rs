@@ -0,0 +1,73 @@
+//! stopi register (AIA extension)
+
+/// stopi register
+///
+/// It reports the highest-priority interrupt that is pending and enabled for Supervisor mode.
+#[derive(Clone, Copy, Debug)]
+pub struct Stopi {
+    bits: usize,
+}
+
+impl From<usize> for Stopi {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Stopi {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Interrupt identity (bits 16-27)
+    ///
+    /// It is the major identity number of the interrupt (i.e., its `scause` code).
+    /// A value of 0 means that no interrupt is pending and enabled.
+    #[inline]
+    pub fn iid(&self) -> usize {
+        (self.bits >> 16) & 0xFFF
+    }
+
+    /// Interrupt priority (bits 0-7)
+    ///
+    /// Lower values denote higher priorities.
+    #[inline]
+    pub fn iprio(&self) -> usize {
+        self.bits & 0xFF
+    }
+
+    /// Returns `true` if an interrupt is pending and enabled
+    #[inline]
+    pub fn is_interrupting(&self) -> bool {
+        self.iid() != 0
+    }
+}
+
+read_csr_as!(Stopi, 0xDB0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopi() {
+        let stopi = Stopi::from(0);
+        assert!(!stopi.is_interrupting());
+        assert_eq!(stopi.iid(), 0);
+        assert_eq!(stopi.iprio(), 0);
+
+        // supervisor external interrupt (9) with priority 5
+        let stopi = Stopi::from((9 << 16) | 5);
+        assert!(stopi.is_interrupting());
+        assert_eq!(stopi.iid(), 9);
+        assert_eq!(stopi.iprio(), 5);
+
+        // reserved bits are ignored
+        let stopi = Stopi::from((0xF << 28) | (0xFFF << 16) | (0xFF << 8) | 0xFF);
+        assert_eq!(stopi.iid(), 0xFFF);
+        assert_eq!(stopi.iprio(), 0xFF);
+    }
+}
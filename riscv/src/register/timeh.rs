@@ -1,3 +1,31 @@
 //! timeh register
+//!
+//! Shadow of the upper 32 bits of the `mtime` memory-mapped register (rv32)
+//! must have `scounteren::tm` or `mcounteren::tm` bit enabled depending on whether
+//! S-mode is implemented or not
+//!
+//! Only available on RV32. On other targets, [`read`] panics.
+//! Use [`crate::register::time::read64`] to read the full 64-bit counter on any target.
 
 read_csr_as_usize_rv32!(0xC81);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(riscv32)]
+    #[test]
+    fn test_read() {
+        // the counter is monotonic, so its upper half never goes backwards
+        let hi = read();
+        let full = crate::register::time::read64();
+        assert!((full >> 32) as usize >= hi);
+    }
+
+    #[cfg(not(riscv32))]
+    #[test]
+    #[should_panic]
+    fn test_read_unsupported() {
+        read();
+    }
+}
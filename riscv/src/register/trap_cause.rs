@@ -0,0 +1,167 @@
+//! Machine trap information snapshot
+
+use super::{mcause, mepc, mtval, mtval2};
+use core::sync::atomic::{compiler_fence, Ordering};
+
+/// Kind of a trap taken into M-mode
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TrapKind {
+    /// Regular trap
+    Trap(mcause::Trap),
+    /// Double trap: an unexpected trap was raised while traps were disabled
+    /// (i.e., in S-mode with `mstatus.SDT` set). It holds the exception code
+    /// of the unexpected trap, as reported in `mtval2`.
+    DoubleTrap(usize),
+}
+
+impl TrapKind {
+    /// Exception code of a double trap (Smdbltrp and Ssdbltrp extensions)
+    pub const DOUBLE_TRAP: usize = 16;
+}
+
+/// Snapshot of the machine trap CSRs of the current hart.
+///
+/// `mcause`, `mtval`, and `mepc` describe the same trap, but they are read with separate
+/// instructions. [`TrapCause::read`] captures all of them as a coherent set in a single call,
+/// reading `mcause`, then `mtval`, then `mepc`, with compiler fences in between.
+///
+/// # Note
+///
+/// The snapshot is only coherent if it is taken before anything can raise another trap
+/// on the current hart (e.g., before re-enabling interrupts in a trap handler).
+#[derive(Clone, Copy, Debug)]
+pub struct TrapCause {
+    /// Saved `mcause` register
+    pub mcause: mcause::Mcause,
+    /// Saved `mtval` register
+    pub mtval: usize,
+    /// Saved `mepc` register
+    pub mepc: usize,
+}
+
+impl TrapCause {
+    /// Reads the machine trap CSRs of the current hart.
+    #[inline]
+    pub fn read() -> Self {
+        Self::read_with(mcause::read, mtval::read, mepc::read)
+    }
+
+    /// Same as [`TrapCause::read`], but using the provided functions to read the CSRs.
+    #[inline]
+    fn read_with<C: FnOnce() -> mcause::Mcause, T: FnOnce() -> usize, E: FnOnce() -> usize>(
+        read_mcause: C,
+        read_mtval: T,
+        read_mepc: E,
+    ) -> Self {
+        let mcause = read_mcause();
+        compiler_fence(Ordering::SeqCst);
+        let mtval = read_mtval();
+        compiler_fence(Ordering::SeqCst);
+        let mepc = read_mepc();
+        compiler_fence(Ordering::SeqCst);
+        Self {
+            mcause,
+            mtval,
+            mepc,
+        }
+    }
+
+    /// Trap Cause
+    #[inline]
+    pub fn cause(&self) -> mcause::Trap {
+        self.mcause.cause()
+    }
+
+    /// Returns the kind of the trap, distinguishing double traps from regular traps.
+    ///
+    /// For double traps, it reads the exception code of the unexpected trap from `mtval2`.
+    /// Thus, it must be called before anything can overwrite `mtval2`.
+    ///
+    /// # Note
+    ///
+    /// A double trap in M-mode (i.e., a trap raised while `mstatus.MDT` is set) never reaches
+    /// the regular trap handler. If the Smrnmi extension is implemented, it is reported to the
+    /// RNMI handler with [`TrapKind::DOUBLE_TRAP`] in `mncause`. Otherwise, the hart enters a critical-error state.
+    #[inline]
+    pub fn kind(&self) -> TrapKind {
+        self.kind_with(|| mtval2::read().bits())
+    }
+
+    /// Same as [`TrapCause::kind`], but using the provided function to read `mtval2`.
+    #[inline]
+    fn kind_with<F: FnOnce() -> usize>(&self, read_mtval2: F) -> TrapKind {
+        match (self.mcause.is_exception(), self.mcause.code()) {
+            (true, TrapKind::DOUBLE_TRAP) => TrapKind::DoubleTrap(read_mtval2()),
+            _ => TrapKind::Trap(self.cause()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_trap_cause_read() {
+        // emulated CSRs, which record the order in which they are read
+        let order = Cell::new(0);
+        let next = || {
+            order.set(order.get() + 1);
+            order.get()
+        };
+
+        let trap = TrapCause::read_with(
+            || {
+                assert_eq!(next(), 1);
+                mcause::Mcause::from(2) // illegal instruction
+            },
+            || {
+                assert_eq!(next(), 2);
+                0x0000_0073
+            },
+            || {
+                assert_eq!(next(), 3);
+                0x8000_1234
+            },
+        );
+
+        assert_eq!(order.get(), 3);
+        assert_eq!(trap.mcause.bits(), 2);
+        assert_eq!(
+            trap.cause(),
+            mcause::Trap::Exception(mcause::Exception::IllegalInstruction)
+        );
+        assert_eq!(trap.mtval, 0x0000_0073);
+        assert_eq!(trap.mepc, 0x8000_1234);
+    }
+
+    #[test]
+    fn test_trap_kind() {
+        let trap = |mcause| TrapCause {
+            mcause: mcause::Mcause::from(mcause),
+            mtval: 0,
+            mepc: 0x8000_1234,
+        };
+
+        // S-mode took a load page fault while SDT was set
+        assert_eq!(
+            trap(TrapKind::DOUBLE_TRAP).kind_with(|| 13),
+            TrapKind::DoubleTrap(13)
+        );
+
+        // regular traps do not read mtval2
+        assert_eq!(
+            trap(2).kind_with(|| unreachable!()),
+            TrapKind::Trap(mcause::Trap::Exception(
+                mcause::Exception::IllegalInstruction
+            ))
+        );
+        // an interrupt with the same code is not a double trap
+        let interrupt = (1 << (usize::BITS - 1)) | TrapKind::DOUBLE_TRAP;
+        assert_eq!(
+            trap(interrupt).kind_with(|| unreachable!()),
+            TrapKind::Trap(mcause::Trap::Interrupt(mcause::Interrupt::Unknown))
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! vcsr register (V extension)
+
+/// vcsr register
+#[derive(Clone, Copy, Debug)]
+pub struct Vcsr {
+    bits: usize,
+}
+
+impl From<usize> for Vcsr {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+/// Fixed-point rounding mode
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum VXRM {
+    /// Round-to-nearest-up
+    RoundToNearestUp = 0b00,
+    /// Round-to-nearest-even
+    RoundToNearestEven = 0b01,
+    /// Round-down (truncate)
+    RoundDown = 0b10,
+    /// Round-to-odd
+    RoundToOdd = 0b11,
+}
+
+impl Vcsr {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Fixed-point accrued saturation flag
+    #[inline]
+    pub fn vxsat(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// Fixed-point rounding mode
+    #[inline]
+    pub fn vxrm(&self) -> VXRM {
+        match (self.bits >> 1) & 0x3 {
+            0b00 => VXRM::RoundToNearestUp,
+            0b01 => VXRM::RoundToNearestEven,
+            0b10 => VXRM::RoundDown,
+            0b11 => VXRM::RoundToOdd,
+            _ => unreachable!(),
+        }
+    }
+}
+
+read_csr_as!(Vcsr, 0x00F);
+write_csr!(0x00F);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// Changing the fixed-point rounding mode affects the result of any fixed-point vector instruction.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vcsr() {
+        let vcsr = Vcsr::from(0);
+        assert!(!vcsr.vxsat());
+        assert_eq!(vcsr.vxrm(), VXRM::RoundToNearestUp);
+
+        let vcsr = Vcsr::from(0b101);
+        assert!(vcsr.vxsat());
+        assert_eq!(vcsr.vxrm(), VXRM::RoundDown);
+
+        assert_eq!(Vcsr::from(0b010).vxrm(), VXRM::RoundToNearestEven);
+        assert_eq!(Vcsr::from(0b110).vxrm(), VXRM::RoundToOdd);
+    }
+}
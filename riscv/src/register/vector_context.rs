@@ -0,0 +1,129 @@
+//! Vector CSRs snapshot for context switches
+
+use super::{vcsr, vl, vstart, vtype};
+
+/// Snapshot of the vector CSRs of the current hart (V extension).
+///
+/// It holds the `vstart`, `vtype`, `vl`, and `vcsr` registers, which must be saved and restored
+/// along with the vector registers on context switches. As `vtype` and `vl` are read-only,
+/// [`VectorContext::restore`] reissues a `vsetvl` instruction with the saved values.
+/// `vstart` is restored last, as `vsetvl` resets it to zero.
+///
+/// The vector registers `v0`-`v31` are **not** part of the snapshot.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct VectorContext {
+    /// Saved `vstart` register
+    pub vstart: usize,
+    /// Saved `vtype` register
+    pub vtype: usize,
+    /// Saved `vl` register
+    pub vl: usize,
+    /// Saved `vcsr` register
+    pub vcsr: usize,
+}
+
+impl VectorContext {
+    /// Saves the vector CSRs of the current hart.
+    ///
+    /// # Note
+    ///
+    /// The vector unit must be enabled (i.e., `mstatus.VS` must not be `Off`).
+    /// Otherwise, accessing the vector CSRs raises an illegal instruction exception.
+    #[inline]
+    pub fn save() -> Self {
+        Self {
+            vstart: vstart::read(),
+            vtype: vtype::read().bits(),
+            vl: vl::read(),
+            vcsr: vcsr::read().bits(),
+        }
+    }
+
+    /// Restores the vector CSRs of the current hart.
+    ///
+    /// # Safety
+    ///
+    /// - The vector unit must be enabled (i.e., `mstatus.VS` must not be `Off`).
+    /// - The snapshot must have been taken on a hart with the same `VLEN`.
+    ///   Otherwise, `vl` may be restored to a different value.
+    /// - Code generated by the compiler assumes that `vstart` is zero. Thus, this function must
+    ///   only be called right before resuming the context that the snapshot was taken from.
+    #[inline]
+    pub unsafe fn restore(&self) {
+        self.restore_with(
+            |avl, vtype| vsetvl(avl, vtype),
+            |bits| vcsr::write(bits),
+            |bits| vstart::write(bits),
+        );
+    }
+
+    /// Same as [`VectorContext::restore`], but using the provided functions to access the CSRs.
+    #[inline]
+    fn restore_with<S: FnOnce(usize, usize), C: FnOnce(usize), T: FnOnce(usize)>(
+        &self,
+        vsetvl: S,
+        write_vcsr: C,
+        write_vstart: T,
+    ) {
+        vsetvl(self.vl, self.vtype);
+        write_vcsr(self.vcsr);
+        write_vstart(self.vstart); // vsetvl resets vstart, so it must be restored last
+    }
+}
+
+/// Sets `vl` and `vtype` with the `vsetvl` instruction.
+///
+/// `vl` is set to `avl` as long as it does not exceed the `VLMAX` of the new `vtype`.
+#[inline]
+#[allow(unused_variables)]
+unsafe fn vsetvl(avl: usize, vtype: usize) {
+    match () {
+        // vsetvl x0, {avl}, {vtype}
+        #[cfg(riscv)]
+        () => core::arch::asm!(".insn r 0x57, 0x7, 0x40, x0, {0}, {1}", in(reg) avl, in(reg) vtype),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_vector_context_restore() {
+        // emulated vector CSRs
+        let (vstart, vtype, vl, vcsr) = (Cell::new(0), Cell::new(0), Cell::new(0), Cell::new(0));
+        let context = VectorContext {
+            vstart: 5,
+            vtype: (1 << 6) | (0b010 << 3), // e32, m1, ta, mu
+            vl: 8,
+            vcsr: 0b101,
+        };
+
+        vstart.set(3); // left by the previous context
+        context.restore_with(
+            |avl, new_vtype| {
+                vtype.set(new_vtype);
+                vl.set(avl);
+                vstart.set(0);
+            },
+            |bits| vcsr.set(bits),
+            |bits| {
+                // vstart is restored after vsetvl
+                assert_eq!(vl.get(), 8);
+                vstart.set(bits)
+            },
+        );
+
+        let restored = VectorContext {
+            vstart: vstart.get(),
+            vtype: vtype.get(),
+            vl: vl.get(),
+            vcsr: vcsr.get(),
+        };
+        assert_eq!(restored, context);
+    }
+}
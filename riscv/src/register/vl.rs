@@ -0,0 +1,5 @@
+//! vl register (V extension)
+//!
+//! `vl` is read-only. It can only be updated with the `vset{i}vl{i}` instructions.
+
+read_csr_as_usize!(0xC20);
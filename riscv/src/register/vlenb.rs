@@ -0,0 +1,5 @@
+//! vlenb register (V extension)
+//!
+//! `vlenb` holds the vector register length in bytes (i.e., VLEN/8).
+
+read_csr_as_usize!(0xC22);
@@ -0,0 +1,142 @@
+//! vsatp register (Hypervisor extension)
+
+pub use super::satp::Mode;
+
+/// vsatp register
+///
+/// It is the VS-mode version of `satp`, and shares its field layout.
+/// It controls the first stage of address translation of a guest (VS-stage).
+#[derive(Clone, Copy, Debug)]
+pub struct Vsatp {
+    bits: usize,
+}
+
+impl From<usize> for Vsatp {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vsatp {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Current address-translation scheme
+    #[inline]
+    #[cfg(target_pointer_width = "32")]
+    pub fn mode(&self) -> Mode {
+        match self.bits & (1 << 31) != 0 {
+            false => Mode::Bare,
+            true => Mode::Sv32,
+        }
+    }
+
+    /// Current address-translation scheme
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn mode(&self) -> Mode {
+        match self.bits >> 60 {
+            0 => Mode::Bare,
+            8 => Mode::Sv39,
+            9 => Mode::Sv48,
+            10 => Mode::Sv57,
+            11 => Mode::Sv64,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Address space identifier
+    #[inline]
+    #[cfg(target_pointer_width = "32")]
+    pub fn asid(&self) -> usize {
+        (self.bits >> 22) & 0x1FF // bits 22-30
+    }
+
+    /// Address space identifier
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn asid(&self) -> usize {
+        self.bits >> 44 & 0xFFFF // bits 44-59
+    }
+
+    /// Physical page number
+    #[inline]
+    #[cfg(target_pointer_width = "32")]
+    pub fn ppn(&self) -> usize {
+        self.bits & 0x3F_FFFF // bits 0-21
+    }
+
+    /// Physical page number
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn ppn(&self) -> usize {
+        self.bits & 0xFFF_FFFF_FFFF // bits 0-43
+    }
+}
+
+read_csr_as!(Vsatp, 0x280);
+write_csr_as_usize!(0x280);
+
+/// Sets the register to corresponding page table mode, physical page number and address space id.
+#[inline]
+#[cfg(target_pointer_width = "32")]
+pub unsafe fn set(mode: Mode, asid: usize, ppn: usize) {
+    assert_eq!(asid, asid & 0x1FF, "invalid value for asid");
+    assert_eq!(ppn, ppn & 0x3F_FFFF, "invalid value for ppn");
+    let bits = (mode as usize) << 31 | (asid << 22) | ppn;
+    _write(bits);
+}
+
+/// Sets the register to corresponding page table mode, physical page number and address space id.
+#[inline]
+#[cfg(target_pointer_width = "64")]
+pub unsafe fn set(mode: Mode, asid: usize, ppn: usize) {
+    assert_eq!(asid, asid & 0xFFFF, "invalid value for asid");
+    assert_eq!(ppn, ppn & 0xFFF_FFFF_FFFF, "invalid value for ppn");
+    let bits = (mode as usize) << 60 | (asid << 44) | ppn;
+    _write(bits);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn test_vsatp() {
+        let vsatp = Vsatp::from(0);
+        assert_eq!(vsatp.mode(), Mode::Bare);
+        assert_eq!(vsatp.asid(), 0);
+        assert_eq!(vsatp.ppn(), 0);
+
+        let vsatp = Vsatp::from((1 << 31) | (0x1A5 << 22) | 0x12_3456);
+        assert_eq!(vsatp.mode(), Mode::Sv32);
+        assert_eq!(vsatp.asid(), 0x1A5);
+        assert_eq!(vsatp.ppn(), 0x12_3456);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_vsatp() {
+        let vsatp = Vsatp::from(0);
+        assert_eq!(vsatp.mode(), Mode::Bare);
+        assert_eq!(vsatp.asid(), 0);
+        assert_eq!(vsatp.ppn(), 0);
+
+        for (bits, mode) in [
+            (8, Mode::Sv39),
+            (9, Mode::Sv48),
+            (10, Mode::Sv57),
+            (11, Mode::Sv64),
+        ] {
+            let vsatp = Vsatp::from((bits << 60) | (0xBEEF << 44) | 0x123_4567_89AB);
+            assert_eq!(vsatp.mode(), mode);
+            assert_eq!(vsatp.asid(), 0xBEEF);
+            assert_eq!(vsatp.ppn(), 0x123_4567_89AB);
+        }
+    }
+}
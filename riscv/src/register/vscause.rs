@@ -0,0 +1,111 @@
+//! vscause register (Hypervisor extension)
+
+pub use super::scause::{Exception, Interrupt, Trap};
+
+/// vscause register
+///
+/// It is the VS-mode version of `scause`, and shares its trap cause encoding.
+#[derive(Clone, Copy)]
+pub struct Vscause {
+    bits: usize,
+}
+
+impl From<usize> for Vscause {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vscause {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the code field
+    #[inline]
+    pub fn code(&self) -> usize {
+        self.bits & !(1 << (usize::BITS as usize - 1))
+    }
+
+    /// Trap Cause
+    #[inline]
+    pub fn cause(&self) -> Trap {
+        if self.is_interrupt() {
+            Trap::Interrupt(Interrupt::from(self.code()))
+        } else {
+            Trap::Exception(Exception::from(self.code()))
+        }
+    }
+
+    /// Is trap cause an interrupt.
+    #[inline]
+    pub fn is_interrupt(&self) -> bool {
+        self.bits & (1 << (usize::BITS as usize - 1)) != 0
+    }
+
+    /// Is trap cause an exception.
+    #[inline]
+    pub fn is_exception(&self) -> bool {
+        !self.is_interrupt()
+    }
+}
+
+read_csr_as!(Vscause, 0x242);
+write_csr!(0x242);
+
+/// Writes the CSR
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
+
+/// Set virtual supervisor cause register to corresponding cause.
+#[inline]
+pub unsafe fn set(cause: Trap) {
+    let bits = match cause {
+        Trap::Interrupt(i) => {
+            let i = usize::try_from(i).expect("unknown interrupt");
+            i | (1 << (usize::BITS as usize - 1)) // interrupt bit is 1
+        }
+        Trap::Exception(e) => usize::try_from(e).expect("unknown exception"),
+    };
+    _write(bits);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vscause() {
+        let interrupt_bit = 1 << (usize::BITS as usize - 1);
+
+        let vscause = Vscause::from(interrupt_bit | 5);
+        assert!(vscause.is_interrupt());
+        assert_eq!(vscause.code(), 5);
+        assert_eq!(vscause.cause(), Trap::Interrupt(Interrupt::SupervisorTimer));
+        assert_eq!(
+            Vscause::from(interrupt_bit | 9).cause(),
+            Trap::Interrupt(Interrupt::SupervisorExternal)
+        );
+        assert_eq!(
+            Vscause::from(interrupt_bit | 3).cause(),
+            Trap::Interrupt(Interrupt::Unknown)
+        );
+
+        let vscause = Vscause::from(13);
+        assert!(vscause.is_exception());
+        assert_eq!(vscause.cause(), Trap::Exception(Exception::LoadPageFault));
+        assert_eq!(
+            Vscause::from(8).cause(),
+            Trap::Exception(Exception::UserEnvCall)
+        );
+        assert_eq!(
+            Vscause::from(14).cause(),
+            Trap::Exception(Exception::Unknown)
+        );
+    }
+}
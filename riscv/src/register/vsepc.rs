@@ -0,0 +1,4 @@
+//! vsepc register (Hypervisor extension)
+
+read_csr_as_usize!(0x241);
+write_csr_as_usize!(0x241);
@@ -0,0 +1,57 @@
+//! vsie register (Hypervisor extension)
+
+/// vsie register
+///
+/// It is the VS-mode version of `sie`. When V=1, `vsie` substitutes for the usual `sie`.
+#[derive(Clone, Copy, Debug)]
+pub struct Vsie {
+    bits: usize,
+}
+
+impl From<usize> for Vsie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vsie {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor Software Interrupt Enable
+    #[inline]
+    pub fn ssoft(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Supervisor Timer Interrupt Enable
+    #[inline]
+    pub fn stimer(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Supervisor External Interrupt Enable
+    #[inline]
+    pub fn sext(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+}
+
+read_csr_as!(Vsie, 0x204);
+write_csr!(0x204);
+set!(0x204);
+clear!(0x204);
+
+set_clear_csr!(
+    /// Supervisor Software Interrupt Enable
+    , set_ssoft, clear_ssoft, 1 << 1);
+set_clear_csr!(
+    /// Supervisor Timer Interrupt Enable
+    , set_stimer, clear_stimer, 1 << 5);
+set_clear_csr!(
+    /// Supervisor External Interrupt Enable
+    , set_sext, clear_sext, 1 << 9);
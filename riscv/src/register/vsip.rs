@@ -0,0 +1,51 @@
+//! vsip register (Hypervisor extension)
+
+/// vsip register
+///
+/// It is the VS-mode version of `sip`. When V=1, `vsip` substitutes for the usual `sip`.
+#[derive(Clone, Copy, Debug)]
+pub struct Vsip {
+    bits: usize,
+}
+
+impl From<usize> for Vsip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vsip {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor Software Interrupt Pending
+    #[inline]
+    pub fn ssoft(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Supervisor Timer Interrupt Pending
+    #[inline]
+    pub fn stimer(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Supervisor External Interrupt Pending
+    #[inline]
+    pub fn sext(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+}
+
+read_csr_as!(Vsip, 0x244);
+write_csr!(0x244);
+set!(0x244);
+clear!(0x244);
+
+set_clear_csr!(
+    /// Supervisor Software Interrupt Pending
+    , set_ssoft, clear_ssoft, 1 << 1);
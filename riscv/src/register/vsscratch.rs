@@ -0,0 +1,4 @@
+//! vsscratch register (Hypervisor extension)
+
+read_csr_as_usize!(0x240);
+write_csr_as_usize!(0x240);
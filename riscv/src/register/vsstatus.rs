@@ -0,0 +1,197 @@
+//! vsstatus register (Hypervisor extension)
+
+pub use super::sstatus::{FS, SPP};
+
+/// Virtual Supervisor Status Register
+///
+/// It is the VS-mode version of `sstatus`, and shares its field layout.
+/// When V=1, `vsstatus` substitutes for the usual `sstatus`.
+#[derive(Clone, Copy, Debug)]
+pub struct Vsstatus {
+    bits: usize,
+}
+
+impl From<usize> for Vsstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vsstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor Interrupt Enable
+    #[inline]
+    pub fn sie(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Supervisor Previous Interrupt Enable
+    #[inline]
+    pub fn spie(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Supervisor Previous Privilege Mode
+    #[inline]
+    pub fn spp(&self) -> SPP {
+        match self.bits & (1 << 8) != 0 {
+            true => SPP::Supervisor,
+            false => SPP::User,
+        }
+    }
+
+    /// The status of the vector unit
+    #[inline]
+    pub fn vs(&self) -> FS {
+        let vs = (self.bits >> 9) & 0x3; // bits 9-10
+        match vs {
+            0 => FS::Off,
+            1 => FS::Initial,
+            2 => FS::Clean,
+            3 => FS::Dirty,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The status of the floating-point unit
+    #[inline]
+    pub fn fs(&self) -> FS {
+        let fs = (self.bits >> 13) & 0x3; // bits 13-14
+        match fs {
+            0 => FS::Off,
+            1 => FS::Initial,
+            2 => FS::Clean,
+            3 => FS::Dirty,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Permit Supervisor User Memory access
+    #[inline]
+    pub fn sum(&self) -> bool {
+        self.bits & (1 << 18) != 0
+    }
+
+    /// Make eXecutable Readable
+    #[inline]
+    pub fn mxr(&self) -> bool {
+        self.bits & (1 << 19) != 0
+    }
+
+    /// S-mode Previous Expected Landing Pad state (Zicfilp extension)
+    #[inline]
+    pub fn spelp(&self) -> bool {
+        self.bits & (1 << 23) != 0
+    }
+
+    /// S-mode Disable Trap (Ssdbltrp extension)
+    #[inline]
+    pub fn sdt(&self) -> bool {
+        self.bits & (1 << 24) != 0
+    }
+}
+
+read_csr_as!(Vsstatus, 0x200);
+write_csr!(0x200);
+set!(0x200);
+clear!(0x200);
+
+set_clear_csr!(
+    /// Supervisor Interrupt Enable
+    , set_sie, clear_sie, 1 << 1);
+set_csr!(
+    /// Supervisor Previous Interrupt Enable
+    , set_spie, 1 << 5);
+set_clear_csr!(
+    /// Permit Supervisor User Memory access
+    , set_sum, clear_sum, 1 << 18);
+set_clear_csr!(
+    /// Make eXecutable Readable
+    , set_mxr, clear_mxr, 1 << 19);
+set_clear_csr!(
+    /// S-mode Previous Expected Landing Pad state
+    , set_spelp, clear_spelp, 1 << 23);
+set_clear_csr!(
+    /// S-mode Disable Trap
+    , set_sdt, clear_sdt, 1 << 24);
+
+/// Supervisor Previous Privilege Mode
+#[inline]
+pub unsafe fn set_spp(spp: SPP) {
+    match spp {
+        SPP::Supervisor => _set(1 << 8),
+        SPP::User => _clear(1 << 8),
+    }
+}
+
+/// The status of the vector unit
+#[inline]
+pub unsafe fn set_vs(vs: FS) {
+    let mut value = _read();
+    value &= !(0x3 << 9); // clear previous value
+    value |= (vs as usize) << 9;
+    _write(value);
+}
+
+/// The status of the floating-point unit
+#[inline]
+pub unsafe fn set_fs(fs: FS) {
+    let mut value = _read();
+    value &= !(0x3 << 13); // clear previous value
+    value |= (fs as usize) << 13;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sstatus::Sstatus;
+    use super::*;
+
+    #[test]
+    fn test_vsstatus_sstatus_parity() {
+        for bits in [
+            0,
+            1 << 1,
+            1 << 5,
+            1 << 8,
+            0b01 << 9,
+            0b10 << 9,
+            0b11 << 13,
+            1 << 18,
+            1 << 19,
+            1 << 23,
+            1 << 24,
+            (1 << 1) | (0b11 << 9) | (0b01 << 13) | (1 << 19),
+            usize::MAX,
+        ] {
+            let (vsstatus, sstatus) = (Vsstatus::from(bits), Sstatus::from(bits));
+            assert_eq!(vsstatus.sie(), sstatus.sie());
+            assert_eq!(vsstatus.spie(), sstatus.spie());
+            assert_eq!(vsstatus.spp(), sstatus.spp());
+            assert_eq!(vsstatus.vs(), sstatus.vs());
+            assert_eq!(vsstatus.fs(), sstatus.fs());
+            assert_eq!(vsstatus.sum(), sstatus.sum());
+            assert_eq!(vsstatus.mxr(), sstatus.mxr());
+            assert_eq!(vsstatus.spelp(), sstatus.spelp());
+            assert_eq!(vsstatus.sdt(), sstatus.sdt());
+        }
+    }
+
+    #[test]
+    fn test_vsstatus() {
+        let vsstatus = Vsstatus::from((1 << 1) | (1 << 8) | (0b11 << 9) | (0b10 << 13));
+        assert!(vsstatus.sie());
+        assert!(!vsstatus.spie());
+        assert_eq!(vsstatus.spp(), SPP::Supervisor);
+        assert_eq!(vsstatus.vs(), FS::Dirty);
+        assert_eq!(vsstatus.fs(), FS::Clean);
+        assert!(!vsstatus.sum());
+        assert!(!vsstatus.mxr());
+    }
+}
@@ -0,0 +1,15 @@
+//! vstart register (V extension)
+
+read_csr_as_usize!(0x008);
+write_csr!(0x008);
+
+/// Writes the CSR
+///
+/// # Safety
+///
+/// Vector instructions start executing at the element index held in `vstart`.
+/// Code generated by the compiler assumes that `vstart` is zero.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
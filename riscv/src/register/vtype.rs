@@ -0,0 +1,137 @@
+//! vtype register (V extension)
+//!
+//! `vtype` is read-only. It can only be updated with the `vset{i}vl{i}` instructions.
+
+/// vtype register
+#[derive(Clone, Copy, Debug)]
+pub struct Vtype {
+    bits: usize,
+}
+
+impl From<usize> for Vtype {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+/// Selected element width
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum VSEW {
+    /// 8-bit elements
+    E8 = 0b000,
+    /// 16-bit elements
+    E16 = 0b001,
+    /// 32-bit elements
+    E32 = 0b010,
+    /// 64-bit elements
+    E64 = 0b011,
+}
+
+/// Vector register group multiplier
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum VLMUL {
+    /// LMUL = 1 (one vector register per group)
+    M1 = 0b000,
+    /// LMUL = 2 (two vector registers per group)
+    M2 = 0b001,
+    /// LMUL = 4 (four vector registers per group)
+    M4 = 0b010,
+    /// LMUL = 8 (eight vector registers per group)
+    M8 = 0b011,
+    /// LMUL = 1/8 (an eighth of a vector register)
+    MF8 = 0b101,
+    /// LMUL = 1/4 (a quarter of a vector register)
+    MF4 = 0b110,
+    /// LMUL = 1/2 (half of a vector register)
+    MF2 = 0b111,
+}
+
+impl Vtype {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Vector register group multiplier
+    #[inline]
+    pub fn vlmul(&self) -> Option<VLMUL> {
+        match self.bits & 0x7 {
+            0b000 => Some(VLMUL::M1),
+            0b001 => Some(VLMUL::M2),
+            0b010 => Some(VLMUL::M4),
+            0b011 => Some(VLMUL::M8),
+            0b101 => Some(VLMUL::MF8),
+            0b110 => Some(VLMUL::MF4),
+            0b111 => Some(VLMUL::MF2),
+            _ => None,
+        }
+    }
+
+    /// Selected element width
+    #[inline]
+    pub fn vsew(&self) -> Option<VSEW> {
+        match (self.bits >> 3) & 0x7 {
+            0b000 => Some(VSEW::E8),
+            0b001 => Some(VSEW::E16),
+            0b010 => Some(VSEW::E32),
+            0b011 => Some(VSEW::E64),
+            _ => None,
+        }
+    }
+
+    /// Vector tail agnostic
+    #[inline]
+    pub fn vta(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Vector mask agnostic
+    #[inline]
+    pub fn vma(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+
+    /// Illegal value (the last `vset{i}vl{i}` requested an unsupported configuration)
+    #[inline]
+    pub fn vill(&self) -> bool {
+        self.bits & (1 << (usize::BITS - 1)) != 0
+    }
+}
+
+read_csr_as!(Vtype, 0xC21);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vtype() {
+        let vtype = Vtype::from(0);
+        assert_eq!(vtype.vlmul(), Some(VLMUL::M1));
+        assert_eq!(vtype.vsew(), Some(VSEW::E8));
+        assert!(!vtype.vta());
+        assert!(!vtype.vma());
+        assert!(!vtype.vill());
+
+        // e32, m4, ta, mu
+        let vtype = Vtype::from((1 << 6) | (0b010 << 3) | 0b010);
+        assert_eq!(vtype.vlmul(), Some(VLMUL::M4));
+        assert_eq!(vtype.vsew(), Some(VSEW::E32));
+        assert!(vtype.vta());
+        assert!(!vtype.vma());
+
+        // e64, mf2, tu, ma
+        let vtype = Vtype::from((1 << 7) | (0b011 << 3) | 0b111);
+        assert_eq!(vtype.vlmul(), Some(VLMUL::MF2));
+        assert_eq!(vtype.vsew(), Some(VSEW::E64));
+        assert!(!vtype.vta());
+        assert!(vtype.vma());
+
+        let vtype = Vtype::from((1 << (usize::BITS - 1)) | (0b100 << 3) | 0b100);
+        assert!(vtype.vill());
+        assert_eq!(vtype.vlmul(), None);
+        assert_eq!(vtype.vsew(), None);
+    }
+}
@@ -0,0 +1,76 @@
+//! Native register width (XLEN) of the target and bit-extension helpers
+
+/// Width in bits of the integer registers of the target (`XLEN`).
+pub const XLEN: usize = usize::BITS as usize;
+
+/// Returns a mask with all the `XLEN` bits set.
+#[inline]
+pub const fn xlen_mask() -> usize {
+    usize::MAX
+}
+
+/// Sign-extends the lowest `bits` bits of `value` to `XLEN` bits.
+///
+/// # Panics
+///
+/// This function panics if `bits` is 0 or greater than [`XLEN`].
+#[inline]
+pub const fn sign_extend(value: usize, bits: usize) -> isize {
+    assert!(bits > 0 && bits <= XLEN);
+    let shift = XLEN - bits;
+    ((value << shift) as isize) >> shift
+}
+
+/// Zero-extends the lowest `bits` bits of `value` to `XLEN` bits.
+///
+/// # Panics
+///
+/// This function panics if `bits` is greater than [`XLEN`].
+#[inline]
+pub const fn zero_extend(value: usize, bits: usize) -> usize {
+    assert!(bits <= XLEN);
+    match bits {
+        XLEN => value,
+        _ => value & ((1 << bits) - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xlen() {
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(XLEN, 32);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(XLEN, 64);
+        assert_eq!(xlen_mask().count_ones() as usize, XLEN);
+    }
+
+    #[test]
+    fn test_sign_extend() {
+        assert_eq!(sign_extend(0b0111, 4), 7);
+        assert_eq!(sign_extend(0b1000, 4), -8);
+        assert_eq!(sign_extend(0b1111, 4), -1);
+        // bits above the field are ignored
+        assert_eq!(sign_extend(0xF0, 4), 0);
+        assert_eq!(sign_extend(0xFFF, 12), -1);
+        assert_eq!(sign_extend(0x7FF, 12), 0x7FF);
+        assert_eq!(sign_extend(1, 1), -1);
+        assert_eq!(sign_extend(usize::MAX, XLEN), -1);
+    }
+
+    #[test]
+    fn test_zero_extend() {
+        assert_eq!(zero_extend(0xFFF, 4), 0xF);
+        assert_eq!(zero_extend(0xFFF, 0), 0);
+        assert_eq!(zero_extend(usize::MAX, XLEN), usize::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sign_extend_zero_bits() {
+        sign_extend(0, 0);
+    }
+}